@@ -0,0 +1,222 @@
+//! Bridges between async streams of bytes and blocking readers and writers.
+//!
+//! Packing and unpacking tar archives uses blocking filesystem I/O, so it runs
+//! on a blocking thread. The bytes are passed to and from the async side over
+//! a bounded channel, so only a few chunks are held in memory at a time.
+
+use bytes::Bytes;
+use futures_util::{
+    future,
+    stream::{self, Stream},
+    StreamExt,
+};
+use std::io::{self, Read, Write};
+use tokio::{runtime::Handle, sync::mpsc};
+
+/// The number of chunks which may be buffered between the async and blocking
+/// sides of a bridge
+const CHANNEL_CAPACITY: usize = 4;
+
+/// The size of the chunks sent by a [`ChannelWriter`]
+const CHUNK_SIZE: usize = 64 * 1024;
+
+/// Feed a stream of bytes to a blocking function which reads them from a
+/// [`Read`].
+///
+/// The function runs on a blocking thread while the stream is polled on the
+/// current task. If the function returns before reading the whole stream, the
+/// rest of the stream is not polled.
+pub(crate) async fn read_stream<S, E, F, T>(stream: S, f: F) -> io::Result<T>
+where
+    S: Stream<Item = Result<Bytes, E>>,
+    E: Into<Box<dyn std::error::Error + Send + Sync>>,
+    F: FnOnce(&mut ChannelReader) -> io::Result<T> + Send + 'static,
+    T: Send + 'static,
+{
+    let (mut sender, receiver) = mpsc::channel(CHANNEL_CAPACITY);
+
+    let mut reader = ChannelReader {
+        handle: Handle::current(),
+        receiver,
+        chunk: Bytes::new(),
+    };
+    let consumer = tokio::task::spawn_blocking(move || f(&mut reader));
+
+    let producer = async move {
+        futures_util::pin_mut!(stream);
+
+        while let Some(chunk) = stream.next().await {
+            let chunk = chunk.map_err(|e| io::Error::new(io::ErrorKind::Other, e));
+            let failed = chunk.is_err();
+
+            // the consumer has finished, or failed, so the rest of the
+            // stream isn't needed
+            if sender.send(chunk).await.is_err() || failed {
+                break;
+            }
+        }
+    };
+
+    let (result, ()) = future::join(consumer, producer).await;
+    result.map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
+}
+
+/// Return a stream of the bytes written by a blocking function to a [`Write`].
+///
+/// The function is started on a blocking thread when the stream is first
+/// polled, so the stream can be created outside of the runtime. If the function
+/// fails, the error is the last item of the stream. If the stream is dropped,
+/// further writes fail with [`io::ErrorKind::BrokenPipe`].
+pub(crate) fn write_stream<F>(f: F) -> impl Stream<Item = io::Result<Bytes>> + Send + 'static
+where
+    F: FnOnce(&mut ChannelWriter) -> io::Result<()> + Send + 'static,
+{
+    let receiver = async move {
+        let (sender, receiver) = mpsc::channel(CHANNEL_CAPACITY);
+
+        let mut writer = ChannelWriter {
+            handle: Handle::current(),
+            sender,
+            buffer: Vec::with_capacity(CHUNK_SIZE),
+        };
+
+        tokio::task::spawn_blocking(move || {
+            if let Err(e) = f(&mut writer).and_then(|()| writer.flush()) {
+                // if the stream has been dropped there is no one to report to
+                let _ = writer.send(Err(e));
+            }
+        });
+
+        receiver
+    };
+
+    stream::once(receiver).flatten()
+}
+
+/// A [`Read`] which receives chunks of bytes from an async task
+#[derive(Debug)]
+pub(crate) struct ChannelReader {
+    handle: Handle,
+    receiver: mpsc::Receiver<io::Result<Bytes>>,
+    chunk: Bytes,
+}
+
+impl Read for ChannelReader {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        while self.chunk.is_empty() {
+            let receiver = &mut self.receiver;
+            match self.handle.block_on(receiver.recv()) {
+                Some(chunk) => self.chunk = chunk?,
+                None => return Ok(0),
+            }
+        }
+
+        let len = buf.len().min(self.chunk.len());
+        buf[..len].copy_from_slice(&self.chunk.split_to(len));
+        Ok(len)
+    }
+}
+
+/// A [`Write`] which sends chunks of [`CHUNK_SIZE`] bytes to an async task
+#[derive(Debug)]
+pub(crate) struct ChannelWriter {
+    handle: Handle,
+    sender: mpsc::Sender<io::Result<Bytes>>,
+    buffer: Vec<u8>,
+}
+
+impl ChannelWriter {
+    fn send(&mut self, chunk: io::Result<Bytes>) -> io::Result<()> {
+        let sender = &mut self.sender;
+        self.handle
+            .block_on(sender.send(chunk))
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "stream was dropped"))
+    }
+}
+
+impl Write for ChannelWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = buf.len().min(CHUNK_SIZE - self.buffer.len());
+        self.buffer.extend_from_slice(&buf[..len]);
+
+        if self.buffer.len() == CHUNK_SIZE {
+            self.flush()?;
+        }
+
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        if self.buffer.is_empty() {
+            return Ok(());
+        }
+
+        let chunk = std::mem::replace(&mut self.buffer, Vec::with_capacity(CHUNK_SIZE));
+        self.send(Ok(chunk.into()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{read_stream, write_stream, CHUNK_SIZE};
+    use bytes::Bytes;
+    use futures_util::{stream, StreamExt, TryStreamExt};
+    use std::io::{self, Read, Write};
+
+    #[tokio::test]
+    async fn read_chunks() {
+        let chunks = (0..10).map(|i| Ok::<_, io::Error>(Bytes::from(vec![i; 1000])));
+
+        let bytes = read_stream(stream::iter(chunks), |reader| {
+            let mut bytes = Vec::new();
+            reader.read_to_end(&mut bytes)?;
+            Ok(bytes)
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(bytes.len(), 10_000);
+        assert_eq!(bytes[9_999], 9);
+    }
+
+    #[tokio::test]
+    async fn stream_error() {
+        let chunks = vec![
+            Ok(Bytes::from_static(b"hello")),
+            Err(io::Error::new(io::ErrorKind::Other, "boom")),
+        ];
+
+        let error = read_stream(stream::iter(chunks), |reader| {
+            reader.read_to_end(&mut Vec::new())
+        })
+        .await
+        .unwrap_err();
+
+        assert_eq!(error.to_string(), "boom");
+    }
+
+    #[tokio::test]
+    async fn write_chunks() {
+        let chunks: Vec<Bytes> =
+            write_stream(|writer| writer.write_all(&vec![7; 3 * CHUNK_SIZE + 1]))
+                .try_collect()
+                .await
+                .unwrap();
+
+        let lengths: Vec<usize> = chunks.iter().map(Bytes::len).collect();
+        assert_eq!(lengths, vec![CHUNK_SIZE, CHUNK_SIZE, CHUNK_SIZE, 1]);
+    }
+
+    #[tokio::test]
+    async fn write_error() {
+        let results: Vec<io::Result<Bytes>> = write_stream(|writer| {
+            writer.write_all(b"hello")?;
+            Err(io::Error::new(io::ErrorKind::Other, "boom"))
+        })
+        .collect()
+        .await;
+
+        assert_eq!(results.len(), 1);
+        assert_eq!(results[0].as_ref().unwrap_err().to_string(), "boom");
+    }
+}
@@ -11,6 +11,12 @@ use containers::Containers;
 pub mod volumes;
 use volumes::Volumes;
 
+pub mod exec;
+use exec::Exec;
+
+mod filters;
+pub use filters::Filters;
+
 /// A Docker client.
 ///
 /// The [`Docker`] client provides top-level API endpoints, and is used to
@@ -37,6 +43,11 @@ impl Docker {
     /// constructs a new Docker instance for a docker host listening at a url
     /// specified by an env var `DOCKER_HOST`, falling back to
     /// `unix:///var/run/docker.sock`
+    ///
+    /// # Panics
+    ///
+    /// Panics if `DOCKER_HOST` is not a valid url, or if it is unset on a
+    /// platform without Unix socket support.
     #[must_use]
     pub fn new() -> Docker {
         match std::env::var("DOCKER_HOST").ok() {
@@ -61,7 +72,12 @@ impl Docker {
 
     /// constructs a new Docker instance for docker host listening at the given
     /// host url
-    #[allow(clippy::single_match_else)]
+    ///
+    /// # Panics
+    ///
+    /// Panics if the url has no host, or if it is a Unix socket on a platform
+    /// without Unix socket support.
+    #[allow(clippy::single_match_else, unknown_lints, unexpected_cfgs)]
     pub fn host(host: &Uri) -> Docker {
         match host.scheme_str() {
             #[cfg(target_os = "linux")]
@@ -94,18 +110,6 @@ impl Docker {
         }
     }
 
-    #[cfg(not(feature = "tls"))]
-    fn tcp(host: String) -> Docker {
-        let http_client = Arc::new(HttpClient::tcp(host));
-        Self { http_client }
-    }
-
-    #[cfg(feature = "tls")]
-    fn tls(host: String) -> Docker {
-        let http_client = Arc::new(HttpClient::tls(host));
-        Self { http_client }
-    }
-
     /// Return an [`Images`] client.
     ///
     /// See the [`Images`] client docs for more details
@@ -150,4 +154,36 @@ impl Docker {
     pub fn volumes(&self) -> Volumes {
         Volumes::new(Arc::clone(&self.http_client))
     }
+
+    /// Return an [`Exec`] client.
+    ///
+    /// See the [`Exec`] client docs for more details
+    ///
+    /// # Example
+    /// ```
+    /// use longshoreman::Docker;
+    ///
+    /// let exec = Docker::new().exec();
+    /// ```
+    #[must_use]
+    pub fn exec(&self) -> Exec {
+        Exec::new(Arc::clone(&self.http_client))
+    }
+}
+
+// the 'tls' feature is reserved for a TLS transport, which isn't implemented
+// yet
+#[allow(unknown_lints, unexpected_cfgs)]
+impl Docker {
+    #[cfg(not(feature = "tls"))]
+    fn tcp(host: String) -> Docker {
+        let http_client = Arc::new(HttpClient::tcp(host));
+        Self { http_client }
+    }
+
+    #[cfg(feature = "tls")]
+    fn tls(host: String) -> Docker {
+        let http_client = Arc::new(HttpClient::tls(host));
+        Self { http_client }
+    }
 }
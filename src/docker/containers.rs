@@ -1,19 +1,90 @@
 //! Endpoints and objects for juggling Docker containers
 
-use crate::http_client::HttpClient;
-use std::sync::Arc;
+use crate::{http_client::HttpClient, Result};
+use std::{path::Path, sync::Arc};
 
 mod create;
 pub use create::{Create, Response as CreateResponse};
 
 mod list;
-pub use list::{List, Response as ListResponse};
+pub use list::{HostConfigSummary, List, Port, Response as ListResponse};
 
 mod remove;
 pub use remove::Remove;
 
 mod inspect;
-pub use inspect::{Inspect, Response as InspectResponse, State, Status};
+pub use inspect::{
+    Health, HealthStatus, HealthcheckResult, HostConfig, Inspect, LogConfig,
+    Response as InspectResponse, State, Status,
+};
+
+mod start;
+pub use start::Start;
+
+mod stop;
+pub use stop::Stop;
+
+mod restart;
+pub use restart::Restart;
+
+mod kill;
+pub use kill::Kill;
+
+mod wait;
+pub use wait::{Condition, Response as WaitResponse, Wait, WaitError};
+
+mod logs;
+pub use logs::Logs;
+
+mod log_output;
+pub(crate) use log_output::Decoder as LogDecoder;
+pub use log_output::LogOutput;
+
+mod attach;
+pub use attach::{Attach, Attached, AttachedInput, AttachedOutput, ResizeHandle};
+
+mod resize;
+pub use resize::Resize;
+
+mod stats;
+pub use stats::{
+    BlkioStatEntry, BlkioStats, CpuStats, CpuUsage, MemoryStats, NetworkStats, PidsStats,
+    Response as StatsResponse, Stats, ThrottlingData,
+};
+
+mod archive;
+pub use archive::{ArchiveGet, ArchivePut, ArchiveStat, PathStat};
+
+mod export;
+pub use export::Export;
+
+mod top;
+pub use top::{Response as TopResponse, Top};
+
+mod changes;
+pub use changes::{Change, Changes, Kind};
+
+mod rename;
+pub use rename::Rename;
+
+mod pause;
+pub use pause::Pause;
+
+mod unpause;
+pub use unpause::Unpause;
+
+mod update;
+pub use update::{Response as UpdateResponse, Update};
+
+mod prune;
+pub use prune::{Prune, Response as PruneResponse};
+
+mod types;
+pub use types::{
+    BindOptions, Config, EndpointSettings, GraphDriver, HealthConfig, Mount, MountPoint, MountType,
+    NetworkSettings, PortBinding, PortType, Resources, RestartPolicy, RestartPolicyName,
+    StateChange, ThrottleDevice, TmpfsOptions, VolumeOptions, WeightDevice,
+};
 
 /// A client to the 'containers' subset of Docker API endpoints
 #[derive(Debug)]
@@ -28,7 +99,7 @@ impl Containers {
 
     /// List local containers
     #[must_use]
-    pub fn list(&self) -> List {
+    pub fn list(&self) -> List<'_> {
         List::new(&self.http_client)
     }
 
@@ -53,4 +124,205 @@ impl Containers {
     pub fn inspect<'a>(&'a self, container: &'a str) -> Inspect<'a> {
         Inspect::new(&self.http_client, container)
     }
+
+    /// Start an existing Docker container
+    ///
+    /// The 'container' parameter may be a name or an id.
+    #[must_use]
+    pub fn start<'a>(&'a self, container: &'a str) -> Start<'a> {
+        Start::new(&self.http_client, container)
+    }
+
+    /// Stop a running Docker container
+    ///
+    /// The 'container' parameter may be a name or an id.
+    #[must_use]
+    pub fn stop<'a>(&'a self, container: &'a str) -> Stop<'a> {
+        Stop::new(&self.http_client, container)
+    }
+
+    /// Restart a Docker container
+    ///
+    /// The 'container' parameter may be a name or an id.
+    #[must_use]
+    pub fn restart<'a>(&'a self, container: &'a str) -> Restart<'a> {
+        Restart::new(&self.http_client, container)
+    }
+
+    /// Send a POSIX signal to a running Docker container
+    ///
+    /// The 'container' parameter may be a name or an id.
+    #[must_use]
+    pub fn kill<'a>(&'a self, container: &'a str) -> Kill<'a> {
+        Kill::new(&self.http_client, container)
+    }
+
+    /// Block until a Docker container stops, then return its exit code
+    ///
+    /// The 'container' parameter may be a name or an id.
+    #[must_use]
+    pub fn wait<'a>(&'a self, container: &'a str) -> Wait<'a> {
+        Wait::new(&self.http_client, container)
+    }
+
+    /// Retrieve the logs of a Docker container
+    ///
+    /// The 'container' parameter may be a name or an id.
+    #[must_use]
+    pub fn logs<'a>(&'a self, container: &'a str) -> Logs<'a> {
+        Logs::new(&self.http_client, container)
+    }
+
+    /// Attach to the stdin, stdout and stderr of a Docker container
+    ///
+    /// The 'container' parameter may be a name or an id.
+    #[must_use]
+    pub fn attach<'a>(&'a self, container: &'a str) -> Attach<'a> {
+        Attach::new(&self.http_client, container)
+    }
+
+    /// Retrieve resource usage statistics of a Docker container
+    ///
+    /// The 'container' parameter may be a name or an id.
+    #[must_use]
+    pub fn stats<'a>(&'a self, container: &'a str) -> Stats<'a> {
+        Stats::new(&self.http_client, container)
+    }
+
+    /// Retrieve a tar archive of a path in the filesystem of a Docker
+    /// container
+    ///
+    /// The 'container' parameter may be a name or an id.
+    #[must_use]
+    pub fn archive_get<'a>(&'a self, container: &'a str, path: &'a str) -> ArchiveGet<'a> {
+        ArchiveGet::new(&self.http_client, container, path)
+    }
+
+    /// Retrieve information about a path in the filesystem of a Docker
+    /// container
+    ///
+    /// The 'container' parameter may be a name or an id.
+    #[must_use]
+    pub fn archive_stat<'a>(&'a self, container: &'a str, path: &'a str) -> ArchiveStat<'a> {
+        ArchiveStat::new(&self.http_client, container, path)
+    }
+
+    /// Extract a tar archive into a directory in the filesystem of a Docker
+    /// container
+    ///
+    /// The 'container' parameter may be a name or an id. The 'path' must be an
+    /// existing directory in the container.
+    #[must_use]
+    pub fn archive_put<'a>(&'a self, container: &'a str, path: &'a str) -> ArchivePut<'a> {
+        ArchivePut::new(&self.http_client, container, path)
+    }
+
+    /// Export the filesystem of a Docker container as a tar archive
+    ///
+    /// The 'container' parameter may be a name or an id.
+    #[must_use]
+    pub fn export<'a>(&'a self, container: &'a str) -> Export<'a> {
+        Export::new(&self.http_client, container)
+    }
+
+    /// Copy a file or directory from the host into a directory in a Docker
+    /// container
+    ///
+    /// The 'container' parameter may be a name or an id. The file or directory
+    /// keeps its name, and is created inside the 'destination' directory, which
+    /// must already exist in the container.
+    pub async fn copy_into(
+        &self,
+        container: &str,
+        source: impl AsRef<Path>,
+        destination: &str,
+    ) -> Result<()> {
+        let tar_archive = archive::pack(source.as_ref().to_owned());
+
+        self.archive_put(container, destination)
+            .send_stream(tar_archive)
+            .await
+    }
+
+    /// Copy a file or directory from a Docker container into a directory on the
+    /// host
+    ///
+    /// The 'container' parameter may be a name or an id. The file or directory
+    /// keeps its name, and is created inside the 'destination' directory, which
+    /// must already exist on the host.
+    pub async fn copy_from(
+        &self,
+        container: &str,
+        source: &str,
+        destination: impl AsRef<Path>,
+    ) -> Result<()> {
+        let (_stat, tar_archive) = self.archive_get(container, source).send().await?;
+
+        archive::unpack(tar_archive, destination.as_ref()).await
+    }
+
+    /// List the processes running inside a Docker container
+    ///
+    /// The 'container' parameter may be a name or an id.
+    #[must_use]
+    pub fn top<'a>(&'a self, container: &'a str) -> Top<'a> {
+        Top::new(&self.http_client, container)
+    }
+
+    /// List the changes to the filesystem of a Docker container, relative to
+    /// its image
+    ///
+    /// The 'container' parameter may be a name or an id.
+    #[must_use]
+    pub fn changes<'a>(&'a self, container: &'a str) -> Changes<'a> {
+        Changes::new(&self.http_client, container)
+    }
+
+    /// Rename a Docker container
+    ///
+    /// The 'container' parameter may be a name or an id.
+    #[must_use]
+    pub fn rename<'a>(&'a self, container: &'a str, name: &'a str) -> Rename<'a> {
+        Rename::new(&self.http_client, container, name)
+    }
+
+    /// Suspend all processes in a running Docker container
+    ///
+    /// The 'container' parameter may be a name or an id.
+    #[must_use]
+    pub fn pause<'a>(&'a self, container: &'a str) -> Pause<'a> {
+        Pause::new(&self.http_client, container)
+    }
+
+    /// Resume all processes in a paused Docker container
+    ///
+    /// The 'container' parameter may be a name or an id.
+    #[must_use]
+    pub fn unpause<'a>(&'a self, container: &'a str) -> Unpause<'a> {
+        Unpause::new(&self.http_client, container)
+    }
+
+    /// Change the resource limits and restart policy of a Docker container
+    /// without restarting it
+    ///
+    /// The 'container' parameter may be a name or an id.
+    #[must_use]
+    pub fn update<'a>(&'a self, container: &'a str) -> Update<'a> {
+        Update::new(&self.http_client, container)
+    }
+
+    /// Delete stopped Docker containers
+    #[must_use]
+    pub fn prune(&self) -> Prune<'_> {
+        Prune::new(&self.http_client)
+    }
+
+    /// Resize the TTY of a Docker container
+    ///
+    /// The 'container' parameter may be a name or an id. The container must
+    /// have been created with a TTY.
+    #[must_use]
+    pub fn resize<'a>(&'a self, container: &'a str, height: u16, width: u16) -> Resize<'a> {
+        Resize::container(&self.http_client, container, height, width)
+    }
 }
@@ -0,0 +1,404 @@
+use crate::{
+    blocking,
+    http_client::{HttpClient, RequestBuilder},
+    utils::empty_string_as_none,
+    Error, Result,
+};
+use chrono::{DateTime, Utc};
+use futures_util::stream::Stream;
+use hyper::{body::Bytes, header::HeaderMap};
+use serde::{Deserialize, Serialize};
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+use tokio::io::AsyncRead;
+
+/// The response header containing the stat information of a path in a
+/// container
+const PATH_STAT_HEADER: &str = "X-Docker-Container-Path-Stat";
+
+/// A request to retrieve a tar archive of a path in the filesystem of a docker
+/// container
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+/// use tokio::stream::StreamExt;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let id = "CONTAINER_ID";
+///
+///     let containers = Docker::new().containers();
+///
+///     let (stat, archive) = containers.archive_get(id, "/etc/hosts").send().await?;
+///     println!("{} is {} bytes", stat.name, stat.size);
+///
+///     let mut archive = Box::pin(archive);
+///     while let Some(chunk) = archive.next().await {
+///         let _bytes = chunk?;
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct ArchiveGet<'a> {
+    http_client: &'a HttpClient,
+    container: &'a str,
+    query: PathQuery<'a>,
+}
+
+impl<'a> ArchiveGet<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, container: &'a str, path: &'a str) -> Self {
+        let query = PathQuery { path };
+        Self {
+            http_client,
+            container,
+            query,
+        }
+    }
+
+    /// Consume the request and return the stat information of the path, along
+    /// with a stream of the bytes of the tar archive
+    pub async fn send(self) -> Result<(PathStat, impl Stream<Item = Result<Bytes>>)> {
+        let endpoint = format!("/containers/{}/archive", self.container);
+        let (headers, stream) = self
+            .http_client
+            .get(endpoint)
+            .query(self.query)
+            .into_headers_and_stream()
+            .await?;
+
+        Ok((PathStat::from_headers(&headers)?, stream))
+    }
+}
+
+/// A request to retrieve the stat information of a path in the filesystem of a
+/// docker container
+#[derive(Debug)]
+pub struct ArchiveStat<'a> {
+    http_client: &'a HttpClient,
+    container: &'a str,
+    query: PathQuery<'a>,
+}
+
+impl<'a> ArchiveStat<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, container: &'a str, path: &'a str) -> Self {
+        let query = PathQuery { path };
+        Self {
+            http_client,
+            container,
+            query,
+        }
+    }
+
+    /// Consume the request and return the stat information of the path
+    pub async fn send(self) -> Result<PathStat> {
+        let endpoint = format!("/containers/{}/archive", self.container);
+        let headers = self
+            .http_client
+            .head(endpoint)
+            .query(self.query)
+            .into_headers()
+            .await?;
+
+        PathStat::from_headers(&headers)
+    }
+}
+
+/// A request to extract a tar archive into a directory in the filesystem of a
+/// docker container
+#[derive(Debug)]
+pub struct ArchivePut<'a> {
+    http_client: &'a HttpClient,
+    container: &'a str,
+    query: PutQuery<'a>,
+}
+
+impl<'a> ArchivePut<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, container: &'a str, path: &'a str) -> Self {
+        let query = PutQuery {
+            path,
+            no_overwrite_dir_non_dir: false,
+            copy_uid_gid: false,
+        };
+        Self {
+            http_client,
+            container,
+            query,
+        }
+    }
+
+    /// Return an error if extracting the archive would replace an existing
+    /// directory with a non-directory, or vice versa.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn no_overwrite_dir_non_dir(mut self, no_overwrite_dir_non_dir: bool) -> Self {
+        self.query.no_overwrite_dir_non_dir = no_overwrite_dir_non_dir;
+        self
+    }
+
+    /// Set the owner of the extracted files to the user and group of the
+    /// container.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn copy_uid_gid(mut self, copy_uid_gid: bool) -> Self {
+        self.query.copy_uid_gid = copy_uid_gid;
+        self
+    }
+
+    /// Consume the request and upload the given tar archive.
+    ///
+    /// The archive may be compressed with gzip, bzip2 or xz.
+    pub async fn send(self, tar_archive: Vec<u8>) -> Result<()> {
+        self.request().tar_body(tar_archive).into_status().await?;
+        Ok(())
+    }
+
+    /// Consume the request and upload a tar archive read from an
+    /// [`AsyncRead`].
+    ///
+    /// The archive may be compressed with gzip, bzip2 or xz.
+    pub async fn send_reader(self, tar_archive: impl AsyncRead + Send + 'static) -> Result<()> {
+        self.request()
+            .tar_reader_body(tar_archive)
+            .into_status()
+            .await?;
+        Ok(())
+    }
+
+    /// Consume the request and upload a tar archive from a stream of chunks.
+    ///
+    /// The archive may be compressed with gzip, bzip2 or xz.
+    pub async fn send_stream<S>(self, tar_archive: S) -> Result<()>
+    where
+        S: Stream<Item = io::Result<Bytes>> + Send + 'static,
+    {
+        self.request()
+            .tar_stream_body(tar_archive)
+            .into_status()
+            .await?;
+        Ok(())
+    }
+
+    fn request(self) -> RequestBuilder<'a> {
+        let endpoint = format!("/containers/{}/archive", self.container);
+        self.http_client.put(endpoint).query(self.query)
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct PathQuery<'a> {
+    path: &'a str,
+}
+
+#[derive(Debug, Serialize)]
+struct PutQuery<'a> {
+    path: &'a str,
+    #[serde(rename = "noOverwriteDirNonDir")]
+    no_overwrite_dir_non_dir: bool,
+    #[serde(rename = "copyUIDGID")]
+    copy_uid_gid: bool,
+}
+
+/// Information about a path in the filesystem of a container
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PathStat {
+    /// The name of the file or directory
+    pub name: String,
+
+    /// The size of the file in bytes
+    pub size: i64,
+
+    /// The file mode, encoded as a Go `os.FileMode`
+    pub mode: u32,
+
+    /// The time the file was last modified
+    pub mtime: DateTime<Utc>,
+
+    /// The target of the path, if it is a symbolic link
+    #[serde(deserialize_with = "empty_string_as_none")]
+    pub link_target: Option<String>,
+}
+
+impl PathStat {
+    const MODE_DIR: u32 = 1 << 31;
+    const MODE_SYMLINK: u32 = 1 << 27;
+
+    /// Whether the path is a directory
+    #[must_use]
+    pub fn is_dir(&self) -> bool {
+        self.mode & Self::MODE_DIR != 0
+    }
+
+    /// Whether the path is a symbolic link
+    #[must_use]
+    pub fn is_symlink(&self) -> bool {
+        self.mode & Self::MODE_SYMLINK != 0
+    }
+
+    /// The unix permission bits of the file
+    #[must_use]
+    pub fn permissions(&self) -> u32 {
+        self.mode & 0o777
+    }
+
+    /// Decode the base64-encoded JSON stat header of an archive response
+    fn from_headers(headers: &HeaderMap) -> Result<Self> {
+        let header = headers.get(PATH_STAT_HEADER).ok_or_else(|| {
+            Error::InvalidResponse(format!("missing '{}' header", PATH_STAT_HEADER))
+        })?;
+
+        let json = base64::decode(header.as_bytes()).map_err(|e| {
+            Error::InvalidResponse(format!("invalid '{}' header: {}", PATH_STAT_HEADER, e))
+        })?;
+
+        Ok(serde_json::from_slice(&json)?)
+    }
+}
+
+/// Pack a file or directory on the host into a tar archive.
+///
+/// The entry is named after the final component of the path, so that it is
+/// extracted into the destination directory with the same name.
+pub(crate) fn pack(path: PathBuf) -> impl Stream<Item = io::Result<Bytes>> + Send + 'static {
+    blocking::write_stream(move |writer| {
+        let name = path.file_name().ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::InvalidInput,
+                format!("'{}' has no file name", path.display()),
+            )
+        })?;
+
+        let mut builder = tar::Builder::new(writer);
+        builder.follow_symlinks(false);
+
+        if path.is_dir() {
+            builder.append_dir_all(name, &path)?;
+        } else {
+            builder.append_path_with_name(&path, name)?;
+        }
+
+        builder.finish()
+    })
+}
+
+/// Unpack a downloaded tar archive into a directory on the host
+pub(crate) async fn unpack(
+    archive: impl Stream<Item = Result<Bytes>>,
+    destination: &Path,
+) -> Result<()> {
+    let destination = destination.to_owned();
+    blocking::read_stream(archive, move |reader| {
+        tar::Archive::new(reader).unpack(destination)
+    })
+    .await?;
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{pack, unpack, PathStat, PATH_STAT_HEADER};
+    use futures_util::{stream, TryStreamExt};
+    use hyper::{
+        body::Bytes,
+        header::{HeaderMap, HeaderValue},
+    };
+
+    #[test]
+    fn decode_path_stat_header() {
+        let json = r#"{"name":"hosts","size":174,"mode":420,"mtime":"2020-05-01T12:00:00.123456789Z","linkTarget":""}"#;
+
+        let mut headers = HeaderMap::new();
+        headers.insert(
+            PATH_STAT_HEADER,
+            HeaderValue::from_str(&base64::encode(json)).unwrap(),
+        );
+
+        let stat = PathStat::from_headers(&headers).unwrap();
+
+        assert_eq!(stat.name, "hosts");
+        assert_eq!(stat.size, 174);
+        assert_eq!(stat.permissions(), 0o644);
+        assert!(!stat.is_dir());
+        assert!(!stat.is_symlink());
+        assert_eq!(stat.link_target, None);
+    }
+
+    #[test]
+    fn directory_mode() {
+        let stat: PathStat = serde_json::from_str(
+            r#"{"name":"etc","size":4096,"mode":2147484141,"mtime":"2020-05-01T12:00:00Z","linkTarget":""}"#,
+        )
+        .unwrap();
+
+        assert!(stat.is_dir());
+        assert_eq!(stat.permissions(), 0o755);
+    }
+
+    #[test]
+    fn missing_header() {
+        assert!(PathStat::from_headers(&HeaderMap::new()).is_err());
+    }
+
+    #[tokio::test]
+    async fn unpack_chunked_archive() {
+        let contents = vec![7_u8; 10_000];
+
+        let mut builder = tar::Builder::new(Vec::new());
+        let mut header = tar::Header::new_gnu();
+        header.set_size(contents.len() as u64);
+        header.set_mode(0o644);
+        builder
+            .append_data(&mut header, "data", contents.as_slice())
+            .unwrap();
+        let archive = builder.into_inner().unwrap();
+
+        let chunks: Vec<_> = archive
+            .chunks(999)
+            .map(|chunk| Ok(Bytes::copy_from_slice(chunk)))
+            .collect();
+
+        let destination =
+            std::env::temp_dir().join(format!("longshoreman-unpack-{}", std::process::id()));
+        std::fs::create_dir_all(&destination).unwrap();
+
+        unpack(stream::iter(chunks), &destination).await.unwrap();
+
+        assert_eq!(std::fs::read(destination.join("data")).unwrap(), contents);
+        std::fs::remove_dir_all(&destination).unwrap();
+    }
+
+    #[tokio::test]
+    async fn pack_and_unpack_directory() {
+        let root = std::env::temp_dir().join(format!("longshoreman-pack-{}", std::process::id()));
+        let _ = std::fs::remove_dir_all(&root);
+
+        let source = root.join("source");
+        let contents = vec![7_u8; 200_000];
+        std::fs::create_dir_all(source.join("nested")).unwrap();
+        std::fs::write(source.join("nested/data"), &contents).unwrap();
+
+        let destination = root.join("destination");
+        std::fs::create_dir_all(&destination).unwrap();
+
+        let chunks: Vec<Bytes> = pack(source).try_collect().await.unwrap();
+        assert!(chunks.len() > 1);
+
+        let chunks = chunks.into_iter().map(Ok);
+        unpack(stream::iter(chunks), &destination).await.unwrap();
+
+        assert_eq!(
+            std::fs::read(destination.join("source/nested/data")).unwrap(),
+            contents
+        );
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+}
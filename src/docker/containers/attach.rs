@@ -0,0 +1,331 @@
+use crate::{
+    containers::{LogDecoder, LogOutput, Resize},
+    http_client::HttpClient,
+    Result,
+};
+use futures_util::stream::Stream;
+use hyper::upgrade::Upgraded;
+use serde::Serialize;
+use std::{
+    io,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::io::{AsyncRead, AsyncWrite, ReadHalf, WriteHalf};
+use tokio_util::codec::FramedRead;
+
+/// A request to attach to the stdin, stdout and stderr of a docker container
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+/// use tokio::{io::AsyncWriteExt, stream::StreamExt};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let id = "CONTAINER_ID";
+///
+///     let containers = Docker::new().containers();
+///
+///     let attached = containers.attach(id).stdin(true).send().await?;
+///
+///     // keep a handle for forwarding changes to the terminal size
+///     let resize_handle = attached.resize_handle();
+///     resize_handle.resize(40, 120).await?;
+///
+///     let (mut output, mut input) = attached.split();
+///
+///     input.write_all(b"echo hello\n").await?;
+///
+///     while let Some(frame) = output.next().await {
+///         print!("{}", frame?);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Attach<'a> {
+    http_client: &'a HttpClient,
+    container: &'a str,
+    query: Query<'a>,
+    tty: Option<bool>,
+}
+
+impl<'a> Attach<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, container: &'a str) -> Self {
+        let query = Query::default();
+        let tty = None;
+        Self {
+            http_client,
+            container,
+            query,
+            tty,
+        }
+    }
+
+    /// Attach to stdin.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn stdin(mut self, stdin: bool) -> Self {
+        self.query.stdin = stdin;
+        self
+    }
+
+    /// Attach to stdout.
+    ///
+    /// default is 'true'.
+    #[must_use]
+    pub fn stdout(mut self, stdout: bool) -> Self {
+        self.query.stdout = stdout;
+        self
+    }
+
+    /// Attach to stderr.
+    ///
+    /// default is 'true'.
+    #[must_use]
+    pub fn stderr(mut self, stderr: bool) -> Self {
+        self.query.stderr = stderr;
+        self
+    }
+
+    /// Replay the logs of the container before streaming new output.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn logs(mut self, logs: bool) -> Self {
+        self.query.logs = logs;
+        self
+    }
+
+    /// Override the key sequence for detaching from the container.
+    ///
+    /// Format is a single character `[a-Z]` or `ctrl-<value>` where `<value>`
+    /// is one of: `a-z`, `@`, `^`, `[`, `,` or `_`.
+    #[must_use]
+    pub fn detach_keys(mut self, detach_keys: &'a str) -> Self {
+        self.query.detach_keys = Some(detach_keys);
+        self
+    }
+
+    /// Whether the container has a TTY attached (see
+    /// [`Config::tty`](crate::containers::Config::tty)), which determines how
+    /// its output is framed.
+    ///
+    /// If unset, the format is guessed from the first bytes of the output.
+    #[must_use]
+    pub fn tty(mut self, tty: bool) -> Self {
+        self.tty = Some(tty);
+        self
+    }
+
+    /// Consume the request builder and return an [`Attached`] session
+    pub async fn send(self) -> Result<Attached<'a>> {
+        let endpoint = format!("/containers/{}/attach", self.container);
+        let upgraded = self
+            .http_client
+            .post(endpoint)
+            .query(self.query)
+            .upgrade()
+            .await?;
+
+        let resize_handle = ResizeHandle::container(self.http_client, self.container);
+
+        Ok(Attached::new(upgraded, resize_handle, self.tty))
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+#[allow(clippy::struct_excessive_bools)]
+struct Query<'a> {
+    stream: bool,
+    stdin: bool,
+    stdout: bool,
+    stderr: bool,
+    logs: bool,
+    detach_keys: Option<&'a str>,
+}
+
+impl Default for Query<'_> {
+    fn default() -> Self {
+        Self {
+            stream: true,
+            stdin: false,
+            stdout: true,
+            stderr: true,
+            logs: false,
+            detach_keys: None,
+        }
+    }
+}
+
+/// An interactive session with a container or exec instance.
+///
+/// The session is a duplex stream of raw bytes. Writing to it writes to stdin
+/// of the attached process. Reading from it returns the (possibly
+/// multiplexed) output of the process.
+///
+/// Use [`Attached::split`] to separate the session into a stream of
+/// demultiplexed [`LogOutput`] frames, and a writer for stdin.
+#[derive(Debug)]
+pub struct Attached<'a> {
+    io: Upgraded,
+    resize_handle: ResizeHandle<'a>,
+    tty: Option<bool>,
+}
+
+impl<'a> Attached<'a> {
+    pub(crate) fn new(io: Upgraded, resize_handle: ResizeHandle<'a>, tty: Option<bool>) -> Self {
+        Self {
+            io,
+            resize_handle,
+            tty,
+        }
+    }
+
+    /// Resize the TTY of the attached container or exec instance
+    pub async fn resize(&self, height: u16, width: u16) -> Result<()> {
+        self.resize_handle.resize(height, width).await
+    }
+
+    /// Return a handle for resizing the TTY of the attached container or exec
+    /// instance.
+    ///
+    /// The handle remains usable after the session is [split](Attached::split),
+    /// so it can be used to forward terminal size changes (such as `SIGWINCH`)
+    /// while the session is in use.
+    #[must_use]
+    pub fn resize_handle(&self) -> ResizeHandle<'a> {
+        self.resize_handle.clone()
+    }
+
+    /// Split the session into a stream of output, and a writer for stdin
+    #[must_use]
+    pub fn split(self) -> (AttachedOutput, AttachedInput) {
+        let (read_half, write_half) = tokio::io::split(self.io);
+
+        let output = AttachedOutput {
+            inner: FramedRead::new(read_half, LogDecoder::new(self.tty)),
+        };
+        let input = AttachedInput { inner: write_half };
+
+        (output, input)
+    }
+}
+
+impl AsyncRead for Attached<'_> {
+    fn poll_read(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &mut [u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.io).poll_read(cx, buf)
+    }
+}
+
+impl AsyncWrite for Attached<'_> {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.io).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.io).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.io).poll_shutdown(cx)
+    }
+}
+
+/// A handle for resizing the TTY of an [`Attached`] session
+#[derive(Debug, Clone)]
+pub struct ResizeHandle<'a> {
+    http_client: &'a HttpClient,
+    target: Target,
+}
+
+#[derive(Debug, Clone)]
+enum Target {
+    Container(String),
+    Exec(String),
+}
+
+impl<'a> ResizeHandle<'a> {
+    pub(crate) fn container(http_client: &'a HttpClient, container: &str) -> Self {
+        let target = Target::Container(container.to_owned());
+        Self {
+            http_client,
+            target,
+        }
+    }
+
+    pub(crate) fn exec(http_client: &'a HttpClient, id: &str) -> Self {
+        let target = Target::Exec(id.to_owned());
+        Self {
+            http_client,
+            target,
+        }
+    }
+
+    /// Resize the TTY of the attached container or exec instance
+    pub async fn resize(&self, height: u16, width: u16) -> Result<()> {
+        let request = match &self.target {
+            Target::Container(container) => {
+                Resize::container(self.http_client, container, height, width)
+            }
+            Target::Exec(id) => Resize::exec(self.http_client, id, height, width),
+        };
+
+        request.send().await
+    }
+}
+
+/// The output half of an [`Attached`] session.
+///
+/// This is a stream of demultiplexed [`LogOutput`] frames.
+#[derive(Debug)]
+pub struct AttachedOutput {
+    inner: FramedRead<ReadHalf<Upgraded>, LogDecoder>,
+}
+
+impl Stream for AttachedOutput {
+    type Item = Result<LogOutput>;
+
+    fn poll_next(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        Pin::new(&mut self.inner).poll_next(cx)
+    }
+}
+
+/// The input half of an [`Attached`] session.
+///
+/// Bytes written to this are forwarded to stdin of the attached process.
+#[derive(Debug)]
+pub struct AttachedInput {
+    inner: WriteHalf<Upgraded>,
+}
+
+impl AsyncWrite for AttachedInput {
+    fn poll_write(
+        mut self: Pin<&mut Self>,
+        cx: &mut Context<'_>,
+        buf: &[u8],
+    ) -> Poll<io::Result<usize>> {
+        Pin::new(&mut self.inner).poll_write(cx, buf)
+    }
+
+    fn poll_flush(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_flush(cx)
+    }
+
+    fn poll_shutdown(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<io::Result<()>> {
+        Pin::new(&mut self.inner).poll_shutdown(cx)
+    }
+}
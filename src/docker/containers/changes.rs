@@ -0,0 +1,127 @@
+use crate::{http_client::HttpClient, Result};
+use serde::{de, Deserialize, Deserializer};
+use std::fmt;
+
+/// A request to list the changes to the filesystem of a docker container,
+/// relative to its image
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let id = "CONTAINER_ID";
+///
+///     let changes = Docker::new().containers().changes(id).send().await?;
+///
+///     for change in changes {
+///         println!("{} {}", change.kind, change.path);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Changes<'a> {
+    http_client: &'a HttpClient,
+    container: &'a str,
+}
+
+impl<'a> Changes<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, container: &'a str) -> Self {
+        Self {
+            http_client,
+            container,
+        }
+    }
+
+    /// Consume the request builder and send the request to the Docker host
+    pub async fn send(self) -> Result<Vec<Change>> {
+        let endpoint = format!("/containers/{}/changes", self.container);
+        let changes: Option<Vec<Change>> = self.http_client.get(endpoint).into_json().await?;
+
+        // Docker returns 'null' if there are no changes
+        Ok(changes.unwrap_or_default())
+    }
+}
+
+/// A change to a path in the filesystem of a container
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Change {
+    /// The path that was changed
+    pub path: String,
+
+    /// The kind of change
+    pub kind: Kind,
+}
+
+/// The kind of change made to a path in the filesystem of a container
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Kind {
+    /// The path was modified
+    Modified,
+
+    /// The path was added
+    Added,
+
+    /// The path was deleted
+    Deleted,
+}
+
+impl<'de> Deserialize<'de> for Kind {
+    fn deserialize<D>(de: D) -> std::result::Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        match u8::deserialize(de)? {
+            0 => Ok(Self::Modified),
+            1 => Ok(Self::Added),
+            2 => Ok(Self::Deleted),
+            other => Err(de::Error::invalid_value(
+                de::Unexpected::Unsigned(other.into()),
+                &"0, 1, or 2",
+            )),
+        }
+    }
+}
+
+impl fmt::Display for Kind {
+    /// Formats the kind in the same way as `docker diff`
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let s = match self {
+            Self::Modified => "C",
+            Self::Added => "A",
+            Self::Deleted => "D",
+        };
+        write!(f, "{}", s)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Change, Kind};
+
+    #[test]
+    fn deserialise_changes() {
+        let changes: Vec<Change> = serde_json::from_str(
+            r#"[
+                {"Path": "/dev", "Kind": 0},
+                {"Path": "/dev/kmsg", "Kind": 1},
+                {"Path": "/test", "Kind": 2}
+            ]"#,
+        )
+        .unwrap();
+
+        let kinds: Vec<Kind> = changes.iter().map(|change| change.kind).collect();
+        assert_eq!(kinds, vec![Kind::Modified, Kind::Added, Kind::Deleted]);
+        assert_eq!(changes[1].path, "/dev/kmsg");
+    }
+
+    #[test]
+    fn invalid_kind() {
+        assert!(serde_json::from_str::<Kind>("3").is_err());
+    }
+}
@@ -1,11 +1,19 @@
-use crate::{http_client::HttpClient, Result};
+use crate::{
+    containers::{HealthConfig, Mount, PortBinding, PortType, Resources, RestartPolicy},
+    http_client::HttpClient,
+    Result,
+};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// A request to create a new docker container
 ///
 /// # Example
 /// ```no_run
-/// use longshoreman::{Docker, Result};
+/// use longshoreman::{
+///     containers::{Mount, PortType, RestartPolicy},
+///     Docker, Result,
+/// };
 ///
 /// #[tokio::main]
 /// async fn main() -> Result<()> {
@@ -18,6 +26,12 @@ use serde::{Deserialize, Serialize};
 ///     containers
 ///         .create("alpine")
 ///         .name("my-cool-container")
+///         .cmd(vec!["sleep", "3600"])
+///         .env("KEY", "value")
+///         .publish(80, PortType::Tcp, 8080)
+///         .mount(Mount::volume("my-volume", "/data"))
+///         .restart_policy(RestartPolicy::on_failure(3))
+///         .memory(64 * 1024 * 1024)
 ///         .send()
 ///         .await?;
 ///
@@ -51,6 +65,247 @@ impl<'a> Create<'a> {
         self
     }
 
+    /// Set the hostname of the container
+    #[must_use]
+    pub fn hostname(mut self, hostname: &'a str) -> Self {
+        self.body.hostname = Some(hostname);
+        self
+    }
+
+    /// Set the command to run in the container.
+    ///
+    /// If unset, the command from the image is used.
+    #[must_use]
+    pub fn cmd(mut self, cmd: impl IntoIterator<Item = &'a str>) -> Self {
+        self.body.cmd = Some(cmd.into_iter().collect());
+        self
+    }
+
+    /// Set the entrypoint of the container.
+    ///
+    /// If unset, the entrypoint from the image is used.
+    #[must_use]
+    pub fn entrypoint(mut self, entrypoint: impl IntoIterator<Item = &'a str>) -> Self {
+        self.body.entrypoint = Some(entrypoint.into_iter().collect());
+        self
+    }
+
+    /// Set an environment variable in the container
+    #[must_use]
+    pub fn env(mut self, key: &str, value: &str) -> Self {
+        self.body.env.push(format!("{}={}", key, value));
+        self
+    }
+
+    /// Set the working directory for commands to run in
+    #[must_use]
+    pub fn working_dir(mut self, working_dir: &'a str) -> Self {
+        self.body.working_dir = Some(working_dir);
+        self
+    }
+
+    /// The user, and optionally the group, that commands are run as inside
+    /// the container.
+    ///
+    /// Format is one of: `user`, `user:group`, `uid`, or `uid:gid`.
+    #[must_use]
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.body.user = Some(user);
+        self
+    }
+
+    /// User-defined metadata
+    #[must_use]
+    pub fn label(mut self, key: &'a str, value: &'a str) -> Self {
+        self.body.labels.insert(key, value);
+        self
+    }
+
+    /// Expose a port of the container, without publishing it to the host
+    #[must_use]
+    pub fn expose(mut self, port: u16, protocol: PortType) -> Self {
+        self.body
+            .exposed_ports
+            .insert(format!("{}/{}", port, protocol), Empty {});
+        self
+    }
+
+    /// Publish a port of the container to a port on the host
+    #[must_use]
+    pub fn publish(self, port: u16, protocol: PortType, host_port: u16) -> Self {
+        let binding = PortBinding {
+            host_ip: None,
+            host_port: Some(host_port.to_string()),
+        };
+        self.port_binding(port, protocol, binding)
+    }
+
+    /// Publish a port of the container to the host, using a custom
+    /// [`PortBinding`]
+    #[must_use]
+    pub fn port_binding(mut self, port: u16, protocol: PortType, binding: PortBinding) -> Self {
+        self = self.expose(port, protocol);
+        self.body
+            .host_config
+            .port_bindings
+            .entry(format!("{}/{}", port, protocol))
+            .or_default()
+            .push(binding);
+        self
+    }
+
+    /// Bind-mount a path on the host into the container.
+    ///
+    /// Use [`Create::mount`] for more control over the mount options.
+    #[must_use]
+    pub fn bind(mut self, host_path: &str, container_path: &str) -> Self {
+        self.body
+            .host_config
+            .binds
+            .push(format!("{}:{}", host_path, container_path));
+        self
+    }
+
+    /// Mount a filesystem into the container
+    #[must_use]
+    pub fn mount(mut self, mount: Mount) -> Self {
+        self.body.host_config.mounts.push(mount);
+        self
+    }
+
+    /// The behaviour to apply when the container exits
+    #[must_use]
+    pub fn restart_policy(mut self, restart_policy: RestartPolicy) -> Self {
+        self.body.host_config.restart_policy = Some(restart_policy);
+        self
+    }
+
+    /// Set the memory limit of the container, in bytes
+    #[must_use]
+    pub fn memory(mut self, memory: i64) -> Self {
+        self.body.host_config.resources.memory = Some(memory);
+        self
+    }
+
+    /// Set the total memory limit (memory + swap) of the container, in bytes.
+    ///
+    /// Set to `-1` to enable unlimited swap.
+    #[must_use]
+    pub fn memory_swap(mut self, memory_swap: i64) -> Self {
+        self.body.host_config.resources.memory_swap = Some(memory_swap);
+        self
+    }
+
+    /// Set the relative CPU weight of the container, compared to other
+    /// containers
+    #[must_use]
+    pub fn cpu_shares(mut self, cpu_shares: i64) -> Self {
+        self.body.host_config.resources.cpu_shares = Some(cpu_shares);
+        self
+    }
+
+    /// Set the CPU quota of the container in units of 10<sup>-9</sup> CPUs
+    #[must_use]
+    pub fn nano_cpus(mut self, nano_cpus: i64) -> Self {
+        self.body.host_config.resources.nano_cpus = Some(nano_cpus);
+        self
+    }
+
+    /// Set the resource limits of the container.
+    ///
+    /// This replaces any limits set by other methods.
+    #[must_use]
+    pub fn resources(mut self, resources: Resources) -> Self {
+        self.body.host_config.resources = resources;
+        self
+    }
+
+    /// Automatically remove the container when it exits.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn auto_remove(mut self, auto_remove: bool) -> Self {
+        self.body.host_config.auto_remove = Some(auto_remove);
+        self
+    }
+
+    /// Give the container extended privileges.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn privileged(mut self, privileged: bool) -> Self {
+        self.body.host_config.privileged = Some(privileged);
+        self
+    }
+
+    /// Add a kernel capability to the container, such as `NET_ADMIN`
+    #[must_use]
+    pub fn cap_add(mut self, capability: &'a str) -> Self {
+        self.body.host_config.cap_add.push(capability);
+        self
+    }
+
+    /// Drop a kernel capability from the container, such as `MKNOD`
+    #[must_use]
+    pub fn cap_drop(mut self, capability: &'a str) -> Self {
+        self.body.host_config.cap_drop.push(capability);
+        self
+    }
+
+    /// Set the network mode of the container.
+    ///
+    /// This may be `bridge`, `host`, `none`, `container:<name|id>`, or the name
+    /// of a custom network.
+    #[must_use]
+    pub fn network_mode(mut self, network_mode: &'a str) -> Self {
+        self.body.host_config.network_mode = Some(network_mode);
+        self
+    }
+
+    /// Connect the container to a network
+    #[must_use]
+    pub fn network(mut self, network: &'a str) -> Self {
+        self.body
+            .networking_config
+            .endpoints_config
+            .insert(network, EndpointConfig::default());
+        self
+    }
+
+    /// Set a test to check that the container is healthy
+    #[must_use]
+    pub fn healthcheck(mut self, healthcheck: HealthConfig) -> Self {
+        self.body.healthcheck = Some(healthcheck);
+        self
+    }
+
+    /// Allocate a pseudo-TTY.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn tty(mut self, tty: bool) -> Self {
+        self.body.tty = Some(tty);
+        self
+    }
+
+    /// Keep stdin open, even if not attached.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn open_stdin(mut self, open_stdin: bool) -> Self {
+        self.body.open_stdin = Some(open_stdin);
+        self
+    }
+
+    /// Close stdin after the first attached client disconnects.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn stdin_once(mut self, stdin_once: bool) -> Self {
+        self.body.stdin_once = Some(stdin_once);
+        self
+    }
+
     /// Consume the request builder and return a [`Response`]
     pub async fn send(self) -> Result<Response> {
         self.http_client
@@ -67,18 +322,101 @@ struct Query<'a> {
     name: Option<&'a str>,
 }
 
+#[allow(clippy::zero_sized_map_values)]
 #[derive(Debug, Serialize)]
 #[serde(rename_all = "PascalCase")]
 struct Body<'a> {
     image: &'a str,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    hostname: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    tty: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    open_stdin: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    stdin_once: Option<bool>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    env: Vec<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    cmd: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    entrypoint: Option<Vec<&'a str>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    working_dir: Option<&'a str>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    labels: HashMap<&'a str, &'a str>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    exposed_ports: HashMap<String, Empty>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    healthcheck: Option<HealthConfig>,
+    host_config: HostConfig<'a>,
+    networking_config: NetworkingConfig<'a>,
 }
 
 impl<'a> Body<'a> {
+    #[allow(clippy::zero_sized_map_values)]
     fn new(image: &'a str) -> Self {
-        Self { image }
+        Self {
+            image,
+            hostname: None,
+            user: None,
+            tty: None,
+            open_stdin: None,
+            stdin_once: None,
+            env: Vec::default(),
+            cmd: None,
+            entrypoint: None,
+            working_dir: None,
+            labels: HashMap::default(),
+            exposed_ports: HashMap::default(),
+            healthcheck: None,
+            host_config: HostConfig::default(),
+            networking_config: NetworkingConfig::default(),
+        }
     }
 }
 
+#[derive(Debug, Default, Serialize)]
+struct Empty {}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct HostConfig<'a> {
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    binds: Vec<String>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    mounts: Vec<Mount>,
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    port_bindings: HashMap<String, Vec<PortBinding>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    restart_policy: Option<RestartPolicy>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    auto_remove: Option<bool>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    privileged: Option<bool>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    cap_add: Vec<&'a str>,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    cap_drop: Vec<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    network_mode: Option<&'a str>,
+    #[serde(flatten)]
+    resources: Resources,
+}
+
+#[allow(clippy::zero_sized_map_values)]
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct NetworkingConfig<'a> {
+    #[serde(skip_serializing_if = "HashMap::is_empty")]
+    endpoints_config: HashMap<&'a str, EndpointConfig>,
+}
+
+#[derive(Debug, Default, Serialize)]
+struct EndpointConfig {}
+
 /// Response returned when creating a new container
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
@@ -92,7 +430,77 @@ pub struct Response {
 
 #[cfg(test)]
 mod tests {
-    use super::Response;
+    use super::{Body, Response};
+    use crate::containers::{HealthConfig, Mount, PortBinding, RestartPolicy};
+    use std::time::Duration;
+
+    #[test]
+    fn serialize_body() {
+        let mut body = Body::new("alpine");
+        body.cmd = Some(vec!["sleep", "3600"]);
+        body.env.push("KEY=value".to_string());
+        body.exposed_ports
+            .insert("80/tcp".to_string(), super::Empty {});
+        body.healthcheck = Some(HealthConfig::cmd_shell("exit 0").interval(Duration::from_secs(1)));
+        body.host_config.port_bindings.insert(
+            "80/tcp".to_string(),
+            vec![PortBinding {
+                host_ip: None,
+                host_port: Some("8080".to_string()),
+            }],
+        );
+        body.host_config
+            .mounts
+            .push(Mount::bind("/host", "/container").read_only(true));
+        body.host_config.restart_policy = Some(RestartPolicy::on_failure(3));
+        body.host_config.resources.memory = Some(1024);
+        body.networking_config
+            .endpoints_config
+            .insert("my-network", super::EndpointConfig {});
+
+        assert_eq!(
+            serde_json::to_value(body).unwrap(),
+            serde_json::json!({
+                "Image": "alpine",
+                "Env": ["KEY=value"],
+                "Cmd": ["sleep", "3600"],
+                "ExposedPorts": {
+                    "80/tcp": {}
+                },
+                "Healthcheck": {
+                    "Test": ["CMD-SHELL", "exit 0"],
+                    "Interval": 1_000_000_000
+                },
+                "HostConfig": {
+                    "Mounts": [
+                        {
+                            "Target": "/container",
+                            "Source": "/host",
+                            "Type": "bind",
+                            "ReadOnly": true
+                        }
+                    ],
+                    "PortBindings": {
+                        "80/tcp": [
+                            {
+                                "HostPort": "8080"
+                            }
+                        ]
+                    },
+                    "RestartPolicy": {
+                        "Name": "on-failure",
+                        "MaximumRetryCount": 3
+                    },
+                    "Memory": 1024
+                },
+                "NetworkingConfig": {
+                    "EndpointsConfig": {
+                        "my-network": {}
+                    }
+                }
+            })
+        );
+    }
 
     #[test]
     fn deserialize_response() {
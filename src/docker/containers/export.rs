@@ -0,0 +1,56 @@
+use crate::{http_client::HttpClient, Result};
+use futures_util::stream::{Stream, TryStreamExt};
+use hyper::body::Bytes;
+use std::io;
+use tokio::io::{stream_reader, AsyncRead};
+
+/// A request to export the filesystem of a docker container as a tar archive
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let id = "CONTAINER_ID";
+///
+///     let containers = Docker::new().containers();
+///
+///     // copy the archive to disk without buffering it in memory
+///     let mut archive = Box::pin(containers.export(id).reader());
+///     let mut file = tokio::fs::File::create("rootfs.tar").await?;
+///     tokio::io::copy(&mut archive, &mut file).await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Export<'a> {
+    http_client: &'a HttpClient,
+    container: &'a str,
+}
+
+impl<'a> Export<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, container: &'a str) -> Self {
+        Self {
+            http_client,
+            container,
+        }
+    }
+
+    /// Consume the request and return the tar archive as a stream of bytes
+    pub fn stream(self) -> impl Stream<Item = Result<Bytes>> + 'a {
+        let endpoint = format!("/containers/{}/export", self.container);
+        self.http_client.get(endpoint).into_stream()
+    }
+
+    /// Consume the request and return the tar archive as an [`AsyncRead`]
+    #[must_use]
+    pub fn reader(self) -> impl AsyncRead + 'a {
+        stream_reader(
+            self.stream()
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e)),
+        )
+    }
+}
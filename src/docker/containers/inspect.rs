@@ -1,7 +1,15 @@
-use crate::{http_client::HttpClient, utils::empty_string_as_none, Result};
+use crate::{
+    containers::{
+        Config, GraphDriver, Mount, MountPoint, NetworkSettings, PortBinding, Resources,
+        RestartPolicy,
+    },
+    http_client::HttpClient,
+    utils::{empty_string_as_none, null_as_default},
+    Result,
+};
 use chrono::{DateTime, Utc};
 use serde::{Deserialize, Serialize};
-use std::path::PathBuf;
+use std::{collections::HashMap, fmt, path::PathBuf};
 
 /// A request to remove an existing docker container
 ///
@@ -73,16 +81,34 @@ struct Query {
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "PascalCase")]
 pub struct Response {
-    /// The security profile of the AppArmor module
+    /// The security profile of the `AppArmor` module
     #[serde(deserialize_with = "empty_string_as_none")]
     pub app_armor_profile: Option<String>,
 
+    /// The arguments passed to the command run in the container
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub args: Vec<String>,
+
+    /// The configuration of the container, which is independent of the host
+    pub config: Config,
+
     /// The datetime that the container was created
     pub created: DateTime<Utc>,
 
     /// The driver running the container
     pub driver: String,
 
+    /// The IDs of exec instances running in the container
+    #[serde(default, rename = "ExecIDs", deserialize_with = "null_as_default")]
+    pub exec_ids: Vec<String>,
+
+    /// The storage driver used for the container's filesystem
+    #[serde(default)]
+    pub graph_driver: Option<GraphDriver>,
+
+    /// The configuration of the container which depends on the host
+    pub host_config: HostConfig,
+
     /// The path on the host to the file containing the container hostname
     pub hostname_path: PathBuf,
 
@@ -98,14 +124,140 @@ pub struct Response {
     /// The unique id of the image which the container is an instance of
     pub image: String,
 
+    /// The `SELinux` label of the container's mounts
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub mount_label: Option<String>,
+
     /// The human-readable name of the container
     pub name: String,
 
+    /// The network settings of the container
+    pub network_settings: NetworkSettings,
+
+    /// The path of the command run in the container
+    pub path: String,
+
+    /// The platform of the container, such as `linux`
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub platform: Option<String>,
+
+    /// The `SELinux` label of the container's process
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub process_label: Option<String>,
+
+    /// The path on the host to the container's `resolv.conf` file
+    pub resolv_conf_path: PathBuf,
+
     /// The number of times the container has been restarted
     pub restart_count: u16,
 
     /// The current state of the container
     pub state: State,
+
+    /// The filesystems mounted into the container
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub mounts: Vec<MountPoint>,
+
+    /// The size of files which have been created or changed in the
+    /// container, in bytes.
+    ///
+    /// Only returned if requested with [`Inspect::size`].
+    #[serde(default)]
+    pub size_rw: Option<i64>,
+
+    /// The total size of all the files in the container, in bytes.
+    ///
+    /// Only returned if requested with [`Inspect::size`].
+    #[serde(default)]
+    pub size_root_fs: Option<i64>,
+}
+
+/// The configuration of a container which depends on the host
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HostConfig {
+    /// Host paths bind-mounted into the container, in the form
+    /// `host-src:container-dest[:options]`
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub binds: Vec<String>,
+
+    /// The filesystems mounted into the container
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub mounts: Vec<Mount>,
+
+    /// The logging configuration of the container
+    #[serde(default)]
+    pub log_config: Option<LogConfig>,
+
+    /// The network mode of the container
+    #[serde(default)]
+    pub network_mode: String,
+
+    /// Bindings of container ports to host ports, keyed by
+    /// `<port>/<protocol>`
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub port_bindings: HashMap<String, Vec<PortBinding>>,
+
+    /// The behaviour to apply when the container exits
+    #[serde(default)]
+    pub restart_policy: Option<RestartPolicy>,
+
+    /// Whether the container is removed when it exits
+    #[serde(default)]
+    pub auto_remove: bool,
+
+    /// Kernel capabilities added to the container
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub cap_add: Vec<String>,
+
+    /// Kernel capabilities dropped from the container
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub cap_drop: Vec<String>,
+
+    /// DNS servers used by the container
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub dns: Vec<String>,
+
+    /// Extra entries in the container's `/etc/hosts` file
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub extra_hosts: Vec<String>,
+
+    /// Whether the container has extended privileges
+    #[serde(default)]
+    pub privileged: bool,
+
+    /// Whether all exposed ports are published to random host ports
+    #[serde(default)]
+    pub publish_all_ports: bool,
+
+    /// Whether the root filesystem of the container is read-only
+    #[serde(default)]
+    pub readonly_rootfs: bool,
+
+    /// The size of `/dev/shm` in bytes
+    #[serde(default)]
+    pub shm_size: Option<i64>,
+
+    /// Kernel parameters set in the container
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub sysctls: HashMap<String, String>,
+
+    /// The resource limits of the container
+    #[serde(flatten)]
+    pub resources: Resources,
+}
+
+/// The logging configuration of a container
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct LogConfig {
+    /// The logging driver, such as `json-file`
+    pub r#type: String,
+
+    /// Driver-specific configuration
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub config: HashMap<String, String>,
 }
 
 /// The state of a docker container
@@ -123,6 +275,14 @@ pub struct State {
     /// The timestamp at which the container finished
     pub finished_at: DateTime<Utc>,
 
+    /// The health of the container, if it has a healthcheck
+    #[serde(default)]
+    pub health: Option<Health>,
+
+    /// True if the container was killed because it ran out of memory
+    #[serde(default, rename = "OOMKilled")]
+    pub oom_killed: bool,
+
     /// True if the container is 'dead'
     pub dead: bool,
 
@@ -146,7 +306,7 @@ pub struct State {
 }
 
 /// The state of a docker container
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "lowercase")]
 pub enum Status {
     /// The container is dead
@@ -163,6 +323,90 @@ pub enum Status {
 
     /// The container has been created, but never started
     Created,
+
+    /// The container is in the process of being removed
+    Removing,
+
+    /// The container has exited
+    Exited,
+}
+
+impl fmt::Display for Status {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Self::Dead => "dead",
+            Self::Paused => "paused",
+            Self::Restarting => "restarting",
+            Self::Running => "running",
+            Self::Created => "created",
+            Self::Removing => "removing",
+            Self::Exited => "exited",
+        };
+        write!(f, "{}", status)
+    }
+}
+
+/// The health of a container
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Health {
+    /// The current health status of the container
+    pub status: HealthStatus,
+
+    /// The number of consecutive failed healthchecks
+    pub failing_streak: u32,
+
+    /// The results of the most recent healthchecks
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub log: Vec<HealthcheckResult>,
+}
+
+/// The health status of a container
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum HealthStatus {
+    /// The container has no healthcheck
+    None,
+
+    /// The container is still starting up
+    Starting,
+
+    /// The container is healthy
+    Healthy,
+
+    /// The container is unhealthy
+    Unhealthy,
+}
+
+impl fmt::Display for HealthStatus {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let status = match self {
+            Self::None => "none",
+            Self::Starting => "starting",
+            Self::Healthy => "healthy",
+            Self::Unhealthy => "unhealthy",
+        };
+        write!(f, "{}", status)
+    }
+}
+
+/// The result of a single healthcheck
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HealthcheckResult {
+    /// The time at which the healthcheck started
+    pub start: DateTime<Utc>,
+
+    /// The time at which the healthcheck finished
+    pub end: DateTime<Utc>,
+
+    /// The exit code of the healthcheck.
+    ///
+    /// `0` is healthy, `1` is unhealthy.
+    pub exit_code: i64,
+
+    /// The output of the healthcheck
+    pub output: String,
 }
 
 #[cfg(test)]
@@ -170,9 +414,9 @@ mod tests {
     use super::*;
 
     #[test]
-    #[allow(clippy::clippy::too_many_lines)]
+    #[allow(clippy::too_many_lines)]
     fn deserialise_response() {
-        let _: Response = serde_json::from_str(
+        let response: Response = serde_json::from_str(
         r#"{
             "AppArmorProfile": "",
             "Args": [
@@ -360,5 +604,20 @@ mod tests {
             ]
           }"#
         ).unwrap();
+
+        assert_eq!(
+            response.network_settings.networks["bridge"].ip_address,
+            Some("172.17.0.2".parse().unwrap())
+        );
+        assert_eq!(response.network_settings.ip_address, None);
+        assert_eq!(response.state.health.unwrap().status, HealthStatus::Healthy);
+        assert_eq!(response.mounts[0].destination, "/data");
+        assert!(!response.mounts[0].rw);
+        assert_eq!(response.config.volumes, vec!["/volumes/data"]);
+        assert_eq!(response.host_config.resources.cpu_period, Some(100_000));
+        assert_eq!(
+            response.host_config.restart_policy,
+            Some(RestartPolicy::on_failure(2))
+        );
     }
 }
@@ -0,0 +1,68 @@
+use crate::{http_client::HttpClient, Result};
+use serde::Serialize;
+
+/// A request to send a POSIX signal to a running docker container
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let id = "CONTAINER_ID";
+///
+///     Docker::new()
+///         .containers()
+///         .kill(id)
+///         .signal("SIGINT")
+///         .send()
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Kill<'a> {
+    http_client: &'a HttpClient,
+    container: &'a str,
+    query: Query<'a>,
+}
+
+impl<'a> Kill<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, container: &'a str) -> Self {
+        let query = Query::default();
+        Self {
+            http_client,
+            container,
+            query,
+        }
+    }
+
+    /// The signal to send to the container.
+    ///
+    /// This may be an integer, or a name such as `SIGINT`. The default is
+    /// `SIGKILL`.
+    #[must_use]
+    pub fn signal(mut self, signal: &'a str) -> Self {
+        self.query.signal = Some(signal);
+        self
+    }
+
+    /// Consume the request builder and send the request to the Docker host
+    pub async fn send(self) -> Result<()> {
+        let endpoint = format!("/containers/{}/kill", self.container);
+        self.http_client
+            .post(endpoint)
+            .query(self.query)
+            .into_status()
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Query<'a> {
+    signal: Option<&'a str>,
+}
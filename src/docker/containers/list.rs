@@ -1,4 +1,9 @@
-use crate::{http_client::HttpClient, Result};
+use crate::{
+    containers::{MountPoint, NetworkSettings, PortType, Status},
+    http_client::HttpClient,
+    utils::null_as_default,
+    Filters, Result,
+};
 use chrono::{serde::ts_seconds, DateTime, Utc};
 use serde::{Deserialize, Serialize};
 use std::{collections::HashMap, net::IpAddr};
@@ -63,6 +68,23 @@ impl<'a> List<'a> {
         self
     }
 
+    /// Return the size of the container as fields `size_rw` and
+    /// `size_root_fs`.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn size(mut self, size: bool) -> Self {
+        self.query.size = size;
+        self
+    }
+
+    /// Only return containers which match the given [`Filters`]
+    #[must_use]
+    pub fn filters(mut self, filters: Filters) -> Self {
+        self.query.filters = filters;
+        self
+    }
+
     /// Consume the request and return a list of Docker containers
     pub async fn send(self) -> Result<Vec<Response>> {
         self.http_client
@@ -78,6 +100,8 @@ struct Query {
     all: bool,
     limit: Option<u16>,
     size: bool,
+    #[serde(skip_serializing_if = "Filters::is_empty")]
+    filters: Filters,
 }
 
 /// A representation of a local Docker container
@@ -97,71 +121,81 @@ pub struct Response {
     #[serde(rename = "ImageID")]
     pub image_id: String,
 
-    command: String,
+    /// The command run in the container
+    pub command: String,
 
     /// The time the container was created
     #[serde(with = "ts_seconds")]
     pub created: DateTime<Utc>,
 
-    ports: Vec<Port>,
-    size_rw: Option<i64>,
-    size_root_fs: Option<i64>,
+    /// The ports exposed by this container
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub ports: Vec<Port>,
+
+    /// The size of files which have been created or changed in the
+    /// container, in bytes
+    #[serde(default)]
+    pub size_rw: Option<i64>,
+
+    /// The total size of all the files in the container, in bytes
+    #[serde(default)]
+    pub size_root_fs: Option<i64>,
 
     /// [Docker 'labels'](https://docs.docker.com/config/labels-custom-metadata/) that exist on this container.
+    #[serde(default, deserialize_with = "null_as_default")]
     pub labels: HashMap<String, String>,
-    state: State,
-    status: String,
-    host_config: HostConfig,
-    network_settings: NetworkSettings,
-    mounts: Vec<Mount>,
+
+    /// The current state of the container
+    pub state: Status,
+
+    /// A human-readable description of the container's status, such as
+    /// `Up 2 hours`
+    pub status: String,
+
+    /// A summary of the host-dependent configuration of the container
+    pub host_config: HostConfigSummary,
+
+    /// The network settings of the container
+    pub network_settings: NetworkSettings,
+
+    /// The filesystems mounted into the container
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub mounts: Vec<MountPoint>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+/// A port exposed by a container
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "PascalCase")]
-struct Port {
+pub struct Port {
+    /// The host IP address that the port is bound to
     #[serde(rename = "IP")]
-    ip: Option<IpAddr>,
-    private_port: u16,
-    public_port: Option<u16>,
-    r#type: PortType,
-}
+    pub ip: Option<IpAddr>,
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "lowercase")]
-enum PortType {
-    Tcp,
-    Udp,
-    Sctp,
-}
+    /// The port inside the container
+    pub private_port: u16,
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "lowercase")]
-enum State {
-    Exited,
-    Created,
-    Running,
-}
+    /// The port on the host, if the port is published
+    pub public_port: Option<u16>,
 
-#[derive(Debug, Clone, Deserialize)]
-#[serde(rename_all = "PascalCase")]
-struct HostConfig {
-    network_mode: String,
+    /// The transport protocol of the port
+    pub r#type: PortType,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+/// A summary of the host-dependent configuration of a container
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
 #[serde(rename_all = "PascalCase")]
-struct NetworkSettings {}
-
-#[derive(Debug, Clone, Deserialize)]
-struct Mount {}
+pub struct HostConfigSummary {
+    /// The network mode of the container
+    pub network_mode: String,
+}
 
 #[cfg(test)]
 mod tests {
-    use super::Response;
+    use super::*;
     #[test]
-    #[allow(clippy::clippy::too_many_lines)]
+    #[allow(clippy::too_many_lines)]
     fn deserialise_response() {
-        let _responses: Vec<Response> = serde_json::from_str(
+        let responses: Vec<Response> = serde_json::from_str(
             r#"
         [
   {
@@ -328,5 +362,36 @@ mod tests {
         "#,
         )
         .unwrap();
+
+        let container = &responses[0];
+        assert_eq!(container.state, Status::Exited);
+        assert_eq!(container.ports[0].public_port, Some(3333));
+        assert_eq!(container.ports[0].r#type, PortType::Tcp);
+        assert_eq!(
+            container.network_settings.networks["bridge"].ip_address,
+            Some("172.17.0.2".parse().unwrap())
+        );
+        assert_eq!(
+            container.network_settings.networks["bridge"].mac_address,
+            Some("02:42:ac:11:00:02".to_string())
+        );
+        assert_eq!(container.mounts[0].source, "/data");
+        assert_eq!(container.mounts[0].mode, "ro,Z");
+    }
+
+    #[test]
+    fn deserialise_state() {
+        for (state, expected) in &[
+            ("created", Status::Created),
+            ("restarting", Status::Restarting),
+            ("running", Status::Running),
+            ("removing", Status::Removing),
+            ("paused", Status::Paused),
+            ("exited", Status::Exited),
+            ("dead", Status::Dead),
+        ] {
+            let status: Status = serde_json::from_str(&format!("\"{}\"", state)).unwrap();
+            assert_eq!(&status, expected);
+        }
     }
 }
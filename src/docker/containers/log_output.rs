@@ -0,0 +1,281 @@
+use crate::{Error, Result};
+use bytes::{Buf, BytesMut};
+use hyper::body::Bytes;
+use std::fmt;
+
+/// The length of the header which prefixes each frame of a multiplexed stream
+const HEADER_LEN: usize = 8;
+
+/// A frame of output from a container
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LogOutput {
+    /// Output which was written to stdin
+    StdIn(Bytes),
+
+    /// Output which was written to stdout
+    StdOut(Bytes),
+
+    /// Output which was written to stderr
+    StdErr(Bytes),
+
+    /// Raw output from a container with a TTY attached.
+    ///
+    /// When a TTY is attached, stdout and stderr are combined into a single
+    /// stream.
+    Console(Bytes),
+}
+
+impl LogOutput {
+    /// Consume the frame and return the raw bytes
+    #[must_use]
+    pub fn into_bytes(self) -> Bytes {
+        match self {
+            Self::StdIn(bytes)
+            | Self::StdOut(bytes)
+            | Self::StdErr(bytes)
+            | Self::Console(bytes) => bytes,
+        }
+    }
+}
+
+impl AsRef<[u8]> for LogOutput {
+    fn as_ref(&self) -> &[u8] {
+        match self {
+            Self::StdIn(bytes)
+            | Self::StdOut(bytes)
+            | Self::StdErr(bytes)
+            | Self::Console(bytes) => bytes.as_ref(),
+        }
+    }
+}
+
+impl fmt::Display for LogOutput {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", String::from_utf8_lossy(self.as_ref()))
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Format {
+    /// Frames are prefixed with a header containing the stream type and the
+    /// frame length
+    Multiplexed,
+
+    /// Bytes are passed through as-is (used by containers with a TTY)
+    Raw,
+}
+
+/// A codec for decoding the output streams of containers and exec instances
+/// into [`LogOutput`] frames.
+///
+/// Docker multiplexes stdout and stderr into a single stream unless a TTY is
+/// attached, in which case the raw output is streamed. If it isn't known
+/// whether a TTY is attached, the format is guessed from the first bytes
+/// received.
+#[derive(Debug, Default)]
+pub(crate) struct Decoder {
+    format: Option<Format>,
+}
+
+impl Decoder {
+    /// Create a decoder for the output of a process which does or doesn't
+    /// have a TTY attached, or whose format should be detected if 'tty' is
+    /// `None`
+    pub fn new(tty: Option<bool>) -> Self {
+        let format = tty.map(|tty| {
+            if tty {
+                Format::Raw
+            } else {
+                Format::Multiplexed
+            }
+        });
+        Self { format }
+    }
+
+    fn detect_format(&mut self, src: &BytesMut) -> Option<Format> {
+        if self.format.is_none() {
+            // The header of a multiplexed frame starts with a stream type of
+            // 0, 1, or 2, followed by three empty bytes
+            let possible_header =
+                src.iter()
+                    .take(4)
+                    .enumerate()
+                    .all(|(i, &byte)| if i == 0 { byte <= 2 } else { byte == 0 });
+
+            if !possible_header {
+                self.format = Some(Format::Raw);
+            } else if src.len() >= HEADER_LEN {
+                self.format = Some(Format::Multiplexed);
+            }
+        }
+
+        self.format
+    }
+}
+
+impl tokio_util::codec::Decoder for Decoder {
+    type Item = LogOutput;
+    type Error = Error;
+
+    fn decode(&mut self, src: &mut BytesMut) -> Result<Option<LogOutput>> {
+        if src.is_empty() {
+            return Ok(None);
+        }
+
+        match self.detect_format(src) {
+            None => Ok(None),
+            Some(Format::Raw) => Ok(Some(LogOutput::Console(src.split().freeze()))),
+            Some(Format::Multiplexed) => {
+                if src.len() < HEADER_LEN {
+                    return Ok(None);
+                }
+
+                let mut length_bytes = [0_u8; 4];
+                length_bytes.copy_from_slice(&src[4..HEADER_LEN]);
+                let length = u32::from_be_bytes(length_bytes) as usize;
+
+                // the length comes from the wire, so space isn't reserved
+                // for it up front; the buffer grows as the frame arrives
+                if src.len() < HEADER_LEN + length {
+                    return Ok(None);
+                }
+
+                let stream_type = src[0];
+                src.advance(HEADER_LEN);
+                let bytes = src.split_to(length).freeze();
+
+                match stream_type {
+                    0 => Ok(Some(LogOutput::StdIn(bytes))),
+                    1 => Ok(Some(LogOutput::StdOut(bytes))),
+                    2 => Ok(Some(LogOutput::StdErr(bytes))),
+                    _ => Err(Error::Decode),
+                }
+            }
+        }
+    }
+
+    fn decode_eof(&mut self, src: &mut BytesMut) -> Result<Option<LogOutput>> {
+        // a stream which ends before a full header is received can't be
+        // multiplexed
+        if self.format.is_none() && !src.is_empty() {
+            self.format = Some(Format::Raw);
+        }
+
+        match self.decode(src)? {
+            Some(frame) => Ok(Some(frame)),
+            None if src.is_empty() => Ok(None),
+            None => Err(Error::Decode),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Decoder, LogOutput};
+    use bytes::BytesMut;
+    use hyper::body::Bytes;
+    use tokio_util::codec::Decoder as _;
+
+    fn frame(stream_type: u8, payload: &[u8]) -> Vec<u8> {
+        #[allow(clippy::cast_possible_truncation)]
+        let length = payload.len() as u32;
+        let mut frame = vec![stream_type, 0, 0, 0];
+        frame.extend_from_slice(&length.to_be_bytes());
+        frame.extend_from_slice(payload);
+        frame
+    }
+
+    #[test]
+    fn decode_multiplexed() {
+        let mut src = BytesMut::new();
+        src.extend_from_slice(&frame(1, b"hello\n"));
+        src.extend_from_slice(&frame(2, b"world\n"));
+
+        let mut decoder = Decoder::default();
+
+        assert_eq!(
+            decoder.decode(&mut src).unwrap(),
+            Some(LogOutput::StdOut(Bytes::from_static(b"hello\n")))
+        );
+        assert_eq!(
+            decoder.decode(&mut src).unwrap(),
+            Some(LogOutput::StdErr(Bytes::from_static(b"world\n")))
+        );
+        assert_eq!(decoder.decode(&mut src).unwrap(), None);
+    }
+
+    #[test]
+    fn decode_partial_frame() {
+        let bytes = frame(1, b"hello\n");
+        let mut src = BytesMut::new();
+        let mut decoder = Decoder::default();
+
+        src.extend_from_slice(&bytes[..3]);
+        assert_eq!(decoder.decode(&mut src).unwrap(), None);
+
+        src.extend_from_slice(&bytes[3..10]);
+        assert_eq!(decoder.decode(&mut src).unwrap(), None);
+
+        src.extend_from_slice(&bytes[10..]);
+        assert_eq!(
+            decoder.decode(&mut src).unwrap(),
+            Some(LogOutput::StdOut(Bytes::from_static(b"hello\n")))
+        );
+    }
+
+    #[test]
+    fn decode_large_frame_header() {
+        let mut src = BytesMut::new();
+        src.extend_from_slice(&[1, 0, 0, 0, 0xff, 0xff, 0xff, 0xff]);
+        let mut decoder = Decoder::new(Some(false));
+
+        assert_eq!(decoder.decode(&mut src).unwrap(), None);
+        assert!(src.capacity() < 1024 * 1024);
+    }
+
+    #[test]
+    fn decode_raw() {
+        let mut src = BytesMut::from(&b"hello world\n"[..]);
+        let mut decoder = Decoder::default();
+
+        assert_eq!(
+            decoder.decode(&mut src).unwrap(),
+            Some(LogOutput::Console(Bytes::from_static(b"hello world\n")))
+        );
+    }
+
+    #[test]
+    fn decode_tty_output_resembling_a_header() {
+        let mut src = BytesMut::from(&b"\x01\0\0\0\0\0\0\x05hello"[..]);
+        let mut decoder = Decoder::new(Some(true));
+
+        assert_eq!(
+            decoder.decode(&mut src).unwrap(),
+            Some(LogOutput::Console(Bytes::from_static(
+                b"\x01\0\0\0\0\0\0\x05hello"
+            )))
+        );
+    }
+
+    #[test]
+    fn decode_known_multiplexed_format() {
+        let bytes = frame(1, b"hi");
+        let mut src = BytesMut::from(&bytes[..2]);
+        let mut decoder = Decoder::new(Some(false));
+
+        assert_eq!(decoder.decode(&mut src).unwrap(), None);
+        assert!(decoder.decode_eof(&mut src).is_err());
+    }
+
+    #[test]
+    fn decode_short_raw_stream() {
+        let mut src = BytesMut::from(&b"\x01"[..]);
+        let mut decoder = Decoder::default();
+
+        assert_eq!(decoder.decode(&mut src).unwrap(), None);
+        assert_eq!(
+            decoder.decode_eof(&mut src).unwrap(),
+            Some(LogOutput::Console(Bytes::from_static(b"\x01")))
+        );
+    }
+}
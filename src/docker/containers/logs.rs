@@ -0,0 +1,164 @@
+use crate::{
+    containers::{log_output::Decoder, LogOutput},
+    http_client::HttpClient,
+    Result,
+};
+use chrono::{DateTime, Utc};
+use futures_util::stream::Stream;
+use serde::Serialize;
+
+/// A request to retrieve the logs of a docker container
+///
+/// Logs are only available for containers using the `json-file` or `journald`
+/// logging drivers.
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{containers::LogOutput, Docker, Result};
+/// use tokio::stream::StreamExt;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let id = "CONTAINER_ID";
+///
+///     let containers = Docker::new().containers();
+///
+///     let mut logs = Box::pin(containers.logs(id).follow(true).tail(10).stream());
+///
+///     while let Some(frame) = logs.next().await {
+///         match frame? {
+///             LogOutput::StdErr(bytes) => eprint!("{}", String::from_utf8_lossy(&bytes)),
+///             output => print!("{}", output),
+///         }
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Logs<'a> {
+    http_client: &'a HttpClient,
+    container: &'a str,
+    query: Query,
+    tty: Option<bool>,
+}
+
+impl<'a> Logs<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, container: &'a str) -> Self {
+        let query = Query::default();
+        let tty = None;
+        Self {
+            http_client,
+            container,
+            query,
+            tty,
+        }
+    }
+
+    /// Keep the stream open, and continue to return new logs as they are
+    /// written.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn follow(mut self, follow: bool) -> Self {
+        self.query.follow = follow;
+        self
+    }
+
+    /// Whether to return logs from stdout.
+    ///
+    /// default is 'true'.
+    #[must_use]
+    pub fn stdout(mut self, stdout: bool) -> Self {
+        self.query.stdout = stdout;
+        self
+    }
+
+    /// Whether to return logs from stderr.
+    ///
+    /// default is 'true'.
+    #[must_use]
+    pub fn stderr(mut self, stderr: bool) -> Self {
+        self.query.stderr = stderr;
+        self
+    }
+
+    /// Only return logs written after this time
+    #[must_use]
+    pub fn since(mut self, since: DateTime<Utc>) -> Self {
+        self.query.since = Some(since.timestamp());
+        self
+    }
+
+    /// Only return logs written before this time
+    #[must_use]
+    pub fn until(mut self, until: DateTime<Utc>) -> Self {
+        self.query.until = Some(until.timestamp());
+        self
+    }
+
+    /// Prefix each line of the logs with a timestamp.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn timestamps(mut self, timestamps: bool) -> Self {
+        self.query.timestamps = timestamps;
+        self
+    }
+
+    /// Only return this number of lines from the end of the logs.
+    ///
+    /// Leave unset to return all logs.
+    #[must_use]
+    pub fn tail(mut self, lines: usize) -> Self {
+        self.query.tail = Some(lines);
+        self
+    }
+
+    /// Whether the container has a TTY attached (see
+    /// [`Config::tty`](crate::containers::Config::tty)), which determines how
+    /// its logs are framed.
+    ///
+    /// If unset, the format is guessed from the first bytes of the logs.
+    #[must_use]
+    pub fn tty(mut self, tty: bool) -> Self {
+        self.tty = Some(tty);
+        self
+    }
+
+    /// Consume the request and return a stream of [`LogOutput`] frames.
+    pub fn stream(self) -> impl Stream<Item = Result<LogOutput>> + 'a {
+        let endpoint = format!("/containers/{}/logs", self.container);
+        self.http_client
+            .get(endpoint)
+            .query(self.query)
+            .decode(Decoder::new(self.tty))
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[allow(clippy::struct_excessive_bools)]
+struct Query {
+    follow: bool,
+    stdout: bool,
+    stderr: bool,
+    since: Option<i64>,
+    until: Option<i64>,
+    timestamps: bool,
+    tail: Option<usize>,
+}
+
+impl Default for Query {
+    fn default() -> Self {
+        Self {
+            follow: false,
+            stdout: true,
+            stderr: true,
+            since: None,
+            until: None,
+            timestamps: false,
+            tail: None,
+        }
+    }
+}
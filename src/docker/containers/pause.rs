@@ -0,0 +1,40 @@
+use crate::{http_client::HttpClient, Result};
+
+/// A request to suspend all processes in a running docker container
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let id = "CONTAINER_ID";
+///
+///     Docker::new().containers().pause(id).send().await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Pause<'a> {
+    http_client: &'a HttpClient,
+    container: &'a str,
+}
+
+impl<'a> Pause<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, container: &'a str) -> Self {
+        Self {
+            http_client,
+            container,
+        }
+    }
+
+    /// Consume the request builder and send the request to the Docker host
+    pub async fn send(self) -> Result<()> {
+        let endpoint = format!("/containers/{}/pause", self.container);
+        self.http_client.post(endpoint).into_status().await?;
+
+        Ok(())
+    }
+}
@@ -0,0 +1,128 @@
+use crate::{http_client::HttpClient, utils::null_as_default, Filters, Result};
+use chrono::{DateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+/// A request to delete all stopped docker containers
+///
+/// # Example
+///
+/// ```no_run
+/// use chrono::{Duration, Utc};
+/// use longshoreman::{Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let response = Docker::new()
+///         .containers()
+///         .prune()
+///         .until(Utc::now() - Duration::hours(24))
+///         .label("ci", "true")
+///         .send()
+///         .await?;
+///
+///     println!(
+///         "deleted {} containers, reclaimed {} bytes",
+///         response.containers_deleted.len(),
+///         response.space_reclaimed
+///     );
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Prune<'a> {
+    http_client: &'a HttpClient,
+    query: Query,
+}
+
+impl<'a> Prune<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient) -> Self {
+        let query = Query::default();
+        Self { http_client, query }
+    }
+
+    /// Only delete containers created before the given time
+    #[must_use]
+    pub fn until(mut self, time: DateTime<Utc>) -> Self {
+        self.query.filters = self.query.filters.until(time);
+        self
+    }
+
+    /// Only delete containers with a label with the given key and value
+    #[must_use]
+    pub fn label(mut self, key: &str, value: &str) -> Self {
+        self.query.filters = self.query.filters.label(key, value);
+        self
+    }
+
+    /// Only delete containers without a label with the given key and value
+    #[must_use]
+    pub fn label_not(mut self, key: &str, value: &str) -> Self {
+        self.query.filters = self.query.filters.label_not(key, value);
+        self
+    }
+
+    /// Only delete containers matching the given filters.
+    ///
+    /// The `until` and `label` filters are supported. This replaces any
+    /// filters set by other methods.
+    #[must_use]
+    pub fn filters(mut self, filters: Filters) -> Self {
+        self.query.filters = filters;
+        self
+    }
+
+    /// Consume the request builder and send the request to the Docker host
+    pub async fn send(self) -> Result<Response> {
+        self.http_client
+            .post("/containers/prune")
+            .query(self.query)
+            .into_json()
+            .await
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Query {
+    #[serde(skip_serializing_if = "Filters::is_empty")]
+    filters: Filters,
+}
+
+/// The result of pruning containers
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Response {
+    /// The IDs of the deleted containers
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub containers_deleted: Vec<String>,
+
+    /// Disk space reclaimed in bytes
+    pub space_reclaimed: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Response;
+
+    #[test]
+    fn deserialise_response() {
+        let response: Response = serde_json::from_str(
+            r#"{
+                "ContainersDeleted": ["e90e34656806", "ae6b2a3f8cc2"],
+                "SpaceReclaimed": 1024
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.containers_deleted.len(), 2);
+        assert_eq!(response.space_reclaimed, 1024);
+    }
+
+    #[test]
+    fn nothing_deleted() {
+        let response: Response =
+            serde_json::from_str(r#"{"ContainersDeleted": null, "SpaceReclaimed": 0}"#).unwrap();
+
+        assert!(response.containers_deleted.is_empty());
+    }
+}
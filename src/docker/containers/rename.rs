@@ -0,0 +1,57 @@
+use crate::{http_client::HttpClient, Result};
+use serde::Serialize;
+
+/// A request to rename a docker container
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let id = "CONTAINER_ID";
+///
+///     Docker::new()
+///         .containers()
+///         .rename(id, "new-name")
+///         .send()
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Rename<'a> {
+    http_client: &'a HttpClient,
+    container: &'a str,
+    query: Query<'a>,
+}
+
+impl<'a> Rename<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, container: &'a str, name: &'a str) -> Self {
+        let query = Query { name };
+        Self {
+            http_client,
+            container,
+            query,
+        }
+    }
+
+    /// Consume the request builder and send the request to the Docker host
+    pub async fn send(self) -> Result<()> {
+        let endpoint = format!("/containers/{}/rename", self.container);
+        self.http_client
+            .post(endpoint)
+            .query(self.query)
+            .into_status()
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Query<'a> {
+    name: &'a str,
+}
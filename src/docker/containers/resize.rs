@@ -0,0 +1,69 @@
+use crate::{http_client::HttpClient, Result};
+use serde::Serialize;
+
+/// A request to resize the TTY of a docker container or exec instance
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let id = "CONTAINER_ID";
+///
+///     Docker::new().containers().resize(id, 40, 120).send().await
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Resize<'a> {
+    http_client: &'a HttpClient,
+    endpoint: String,
+    query: Query,
+}
+
+impl<'a> Resize<'a> {
+    pub(crate) fn container(
+        http_client: &'a HttpClient,
+        container: &str,
+        height: u16,
+        width: u16,
+    ) -> Self {
+        let endpoint = format!("/containers/{}/resize", container);
+        Self::new(http_client, endpoint, height, width)
+    }
+
+    pub(crate) fn exec(http_client: &'a HttpClient, id: &str, height: u16, width: u16) -> Self {
+        let endpoint = format!("/exec/{}/resize", id);
+        Self::new(http_client, endpoint, height, width)
+    }
+
+    fn new(http_client: &'a HttpClient, endpoint: String, height: u16, width: u16) -> Self {
+        let query = Query {
+            h: height,
+            w: width,
+        };
+        Self {
+            http_client,
+            endpoint,
+            query,
+        }
+    }
+
+    /// Consume the request builder and send the request to the Docker host
+    pub async fn send(self) -> Result<()> {
+        self.http_client
+            .post(self.endpoint)
+            .query(self.query)
+            .into_status()
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Query {
+    h: u16,
+    w: u16,
+}
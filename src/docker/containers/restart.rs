@@ -0,0 +1,70 @@
+use crate::{http_client::HttpClient, Result};
+use serde::Serialize;
+use std::time::Duration;
+
+/// A request to restart a docker container
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let id = "CONTAINER_ID";
+///
+///     Docker::new()
+///         .containers()
+///         .restart(id)
+///         .timeout(Duration::from_secs(5))
+///         .send()
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Restart<'a> {
+    http_client: &'a HttpClient,
+    container: &'a str,
+    query: Query,
+}
+
+impl<'a> Restart<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, container: &'a str) -> Self {
+        let query = Query::default();
+        Self {
+            http_client,
+            container,
+            query,
+        }
+    }
+
+    /// The time to wait before killing the container.
+    ///
+    /// The duration is rounded down to the nearest second. If unset, the
+    /// container's configured stop timeout is used.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.query.t = Some(timeout.as_secs());
+        self
+    }
+
+    /// Consume the request builder and send the request to the Docker host
+    pub async fn send(self) -> Result<()> {
+        let endpoint = format!("/containers/{}/restart", self.container);
+        self.http_client
+            .post(endpoint)
+            .query(self.query)
+            .into_status()
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Query {
+    t: Option<u64>,
+}
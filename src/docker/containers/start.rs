@@ -0,0 +1,76 @@
+use crate::{containers::StateChange, http_client::HttpClient, Result};
+use serde::Serialize;
+
+/// A request to start an existing docker container
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{containers::StateChange, Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let id = "CONTAINER_ID";
+///
+///     let state_change = Docker::new()
+///         .containers()
+///         .start(id)
+///         .send()
+///         .await?;
+///
+///     if state_change == StateChange::Unchanged {
+///         println!("container was already running");
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Start<'a> {
+    http_client: &'a HttpClient,
+    container: &'a str,
+    query: Query<'a>,
+}
+
+impl<'a> Start<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, container: &'a str) -> Self {
+        let query = Query::default();
+        Self {
+            http_client,
+            container,
+            query,
+        }
+    }
+
+    /// Override the key sequence for detaching a container.
+    ///
+    /// Format is a single character `[a-Z]` or `ctrl-<value>` where `<value>`
+    /// is one of: `a-z`, `@`, `^`, `[`, `,` or `_`.
+    #[must_use]
+    pub fn detach_keys(mut self, detach_keys: &'a str) -> Self {
+        self.query.detach_keys = Some(detach_keys);
+        self
+    }
+
+    /// Consume the request builder and send the request to the Docker host.
+    ///
+    /// Returns [`StateChange::Unchanged`] if the container was already
+    /// started.
+    pub async fn send(self) -> Result<StateChange> {
+        let endpoint = format!("/containers/{}/start", self.container);
+        let status = self
+            .http_client
+            .post(endpoint)
+            .query(self.query)
+            .into_status()
+            .await?;
+
+        Ok(StateChange::from_status(status))
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct Query<'a> {
+    detach_keys: Option<&'a str>,
+}
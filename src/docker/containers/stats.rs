@@ -0,0 +1,568 @@
+use crate::{http_client::HttpClient, utils::null_as_default, Result};
+use chrono::{DateTime, Utc};
+use futures_util::stream::Stream;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A request to retrieve resource usage statistics of a docker container
+///
+/// # Examples
+///
+/// ## One-shot
+///
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let id = "CONTAINER_ID";
+///
+///     let stats = Docker::new().containers().stats(id).send().await?;
+///
+///     println!("CPU: {:.2}%", stats.cpu_percent());
+///     println!("memory: {:.2}%", stats.memory_percent());
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// ## Streaming
+///
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+/// use tokio::stream::StreamExt;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let id = "CONTAINER_ID";
+///     let containers = Docker::new().containers();
+///
+///     let mut stream = Box::pin(containers.stats(id).stream());
+///
+///     while let Some(stats) = stream.next().await {
+///         let (rx, tx) = stats?.network_io();
+///         println!("network I/O: {}B / {}B", rx, tx);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Stats<'a> {
+    http_client: &'a HttpClient,
+    container: &'a str,
+    query: Query,
+}
+
+impl<'a> Stats<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, container: &'a str) -> Self {
+        let query = Query::default();
+        Self {
+            http_client,
+            container,
+            query,
+        }
+    }
+
+    /// Return the statistics immediately, without waiting for a second sample
+    /// to be taken.
+    ///
+    /// This makes [`Stats::send`] faster, but the `precpu_stats` will be
+    /// empty, so [`Response::cpu_percent`] can't be computed. This has no
+    /// effect when streaming.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn one_shot(mut self, one_shot: bool) -> Self {
+        self.query.one_shot = one_shot;
+        self
+    }
+
+    /// Consume the request and return a single sample of the container's
+    /// resource usage
+    pub async fn send(mut self) -> Result<Response> {
+        self.query.stream = false;
+
+        let endpoint = format!("/containers/{}/stats", self.container);
+        self.http_client
+            .get(endpoint)
+            .query(self.query)
+            .into_json()
+            .await
+    }
+
+    /// Consume the request and return a stream of samples of the container's
+    /// resource usage.
+    ///
+    /// A new sample is returned roughly every second.
+    pub fn stream(mut self) -> impl Stream<Item = Result<Response>> + 'a {
+        self.query.stream = true;
+        self.query.one_shot = false;
+
+        let endpoint = format!("/containers/{}/stats", self.container);
+        self.http_client
+            .get(endpoint)
+            .query(self.query)
+            .into_stream_json()
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "kebab-case")]
+struct Query {
+    stream: bool,
+    one_shot: bool,
+}
+
+/// A sample of the resource usage of a container
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+pub struct Response {
+    /// The name of the container
+    #[serde(default)]
+    pub name: String,
+
+    /// The unique ID of the container
+    #[serde(default)]
+    pub id: String,
+
+    /// The time at which the sample was taken
+    pub read: DateTime<Utc>,
+
+    /// The time at which the previous sample was taken
+    pub preread: DateTime<Utc>,
+
+    /// Statistics about the number of processes in the container
+    #[serde(default)]
+    pub pids_stats: PidsStats,
+
+    /// Network statistics, keyed by network interface
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub networks: HashMap<String, NetworkStats>,
+
+    /// Memory statistics
+    #[serde(default)]
+    pub memory_stats: MemoryStats,
+
+    /// Block IO statistics
+    #[serde(default)]
+    pub blkio_stats: BlkioStats,
+
+    /// CPU statistics
+    pub cpu_stats: CpuStats,
+
+    /// CPU statistics from the previous sample
+    pub precpu_stats: CpuStats,
+}
+
+impl Response {
+    /// The CPU usage of the container as a percentage of a single CPU,
+    /// calculated in the same way as `docker stats`.
+    ///
+    /// A container using two CPUs fully will report 200%.
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn cpu_percent(&self) -> f64 {
+        let cpu_delta = self
+            .cpu_stats
+            .cpu_usage
+            .total_usage
+            .saturating_sub(self.precpu_stats.cpu_usage.total_usage);
+
+        let system_delta = self
+            .cpu_stats
+            .system_cpu_usage
+            .unwrap_or_default()
+            .saturating_sub(self.precpu_stats.system_cpu_usage.unwrap_or_default());
+
+        let online_cpus = match self.cpu_stats.online_cpus {
+            Some(online_cpus) if online_cpus > 0 => u64::from(online_cpus),
+            _ => self.cpu_stats.cpu_usage.percpu_usage.len() as u64,
+        };
+
+        if cpu_delta > 0 && system_delta > 0 {
+            (cpu_delta as f64 / system_delta as f64) * online_cpus as f64 * 100.0
+        } else {
+            0.0
+        }
+    }
+
+    /// The memory used by the container in bytes, excluding the page cache.
+    ///
+    /// This is calculated in the same way as `docker stats`, by subtracting
+    /// the inactive file cache from the total usage.
+    #[must_use]
+    pub fn memory_usage(&self) -> u64 {
+        let usage = self.memory_stats.usage.unwrap_or_default();
+        let stats = &self.memory_stats.stats;
+
+        // cgroups v1 reports 'total_inactive_file', cgroups v2 reports
+        // 'inactive_file'
+        let inactive_file = stats
+            .get("total_inactive_file")
+            .or_else(|| stats.get("inactive_file"))
+            .copied()
+            .unwrap_or_default();
+
+        usage.saturating_sub(inactive_file)
+    }
+
+    /// The memory used by the container, excluding the page cache, as a
+    /// percentage of the memory limit
+    #[must_use]
+    #[allow(clippy::cast_precision_loss)]
+    pub fn memory_percent(&self) -> f64 {
+        match self.memory_stats.limit {
+            Some(limit) if limit > 0 => self.memory_usage() as f64 / limit as f64 * 100.0,
+            _ => 0.0,
+        }
+    }
+
+    /// The total number of bytes (received, transmitted) over all network
+    /// interfaces
+    #[must_use]
+    pub fn network_io(&self) -> (u64, u64) {
+        self.networks.values().fold((0, 0), |(rx, tx), network| {
+            (rx + network.rx_bytes, tx + network.tx_bytes)
+        })
+    }
+
+    /// The total number of bytes (read, written) to block devices
+    #[must_use]
+    pub fn block_io(&self) -> (u64, u64) {
+        self.blkio_stats
+            .io_service_bytes_recursive
+            .iter()
+            .fold((0, 0), |(read, write), entry| {
+                match entry.op.to_lowercase().as_str() {
+                    "read" => (read + entry.value, write),
+                    "write" => (read, write + entry.value),
+                    _ => (read, write),
+                }
+            })
+    }
+}
+
+/// Statistics about the number of processes in a container
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct PidsStats {
+    /// The number of processes in the container
+    #[serde(default)]
+    pub current: Option<u64>,
+
+    /// The maximum number of processes allowed in the container
+    #[serde(default)]
+    pub limit: Option<u64>,
+}
+
+/// Network statistics for a single network interface
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct NetworkStats {
+    /// Bytes received
+    pub rx_bytes: u64,
+
+    /// Packets received
+    pub rx_packets: u64,
+
+    /// Received packets with errors
+    pub rx_errors: u64,
+
+    /// Incoming packets dropped
+    pub rx_dropped: u64,
+
+    /// Bytes transmitted
+    pub tx_bytes: u64,
+
+    /// Packets transmitted
+    pub tx_packets: u64,
+
+    /// Transmitted packets with errors
+    pub tx_errors: u64,
+
+    /// Outgoing packets dropped
+    pub tx_dropped: u64,
+}
+
+/// Memory statistics of a container
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct MemoryStats {
+    /// The current memory usage in bytes, including the page cache
+    #[serde(default)]
+    pub usage: Option<u64>,
+
+    /// The maximum memory usage in bytes (not available with cgroups v2)
+    #[serde(default)]
+    pub max_usage: Option<u64>,
+
+    /// The memory limit in bytes
+    #[serde(default)]
+    pub limit: Option<u64>,
+
+    /// The number of times the memory limit was hit
+    #[serde(default)]
+    pub failcnt: Option<u64>,
+
+    /// Detailed, cgroup-specific memory statistics
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub stats: HashMap<String, u64>,
+}
+
+/// Block IO statistics of a container
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct BlkioStats {
+    /// Bytes transferred to and from block devices
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub io_service_bytes_recursive: Vec<BlkioStatEntry>,
+
+    /// IO operations performed on block devices
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub io_serviced_recursive: Vec<BlkioStatEntry>,
+}
+
+/// A single block IO statistic for a device
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct BlkioStatEntry {
+    /// The major number of the device
+    pub major: u64,
+
+    /// The minor number of the device
+    pub minor: u64,
+
+    /// The operation, such as `read` or `write`
+    pub op: String,
+
+    /// The value of the statistic
+    pub value: u64,
+}
+
+/// CPU statistics of a container
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct CpuStats {
+    /// CPU usage of the container
+    #[serde(default)]
+    pub cpu_usage: CpuUsage,
+
+    /// The total CPU usage of the host in nanoseconds
+    #[serde(default)]
+    pub system_cpu_usage: Option<u64>,
+
+    /// The number of CPUs online on the host
+    #[serde(default)]
+    pub online_cpus: Option<u32>,
+
+    /// Statistics about CPU throttling of the container
+    #[serde(default)]
+    pub throttling_data: ThrottlingData,
+}
+
+/// CPU usage of a container
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct CpuUsage {
+    /// Total CPU time consumed in nanoseconds
+    #[serde(default)]
+    pub total_usage: u64,
+
+    /// CPU time consumed per core in nanoseconds (not available with cgroups
+    /// v2)
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub percpu_usage: Vec<u64>,
+
+    /// CPU time consumed in kernel mode in nanoseconds
+    #[serde(default)]
+    pub usage_in_kernelmode: u64,
+
+    /// CPU time consumed in user mode in nanoseconds
+    #[serde(default)]
+    pub usage_in_usermode: u64,
+}
+
+/// Statistics about CPU throttling of a container
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+pub struct ThrottlingData {
+    /// The number of periods with throttling active
+    #[serde(default)]
+    pub periods: u64,
+
+    /// The number of periods in which the container hit its throttling limit
+    #[serde(default)]
+    pub throttled_periods: u64,
+
+    /// The total time that the container was throttled for, in nanoseconds
+    #[serde(default)]
+    pub throttled_time: u64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn deserialise_response() {
+        let response: Response = serde_json::from_str(
+            r#"{
+                "read": "2015-01-08T22:57:31.547920715Z",
+                "preread": "2015-01-08T22:57:30.547920715Z",
+                "pids_stats": {
+                    "current": 3
+                },
+                "networks": {
+                    "eth0": {
+                        "rx_bytes": 5338,
+                        "rx_dropped": 0,
+                        "rx_errors": 0,
+                        "rx_packets": 36,
+                        "tx_bytes": 648,
+                        "tx_dropped": 0,
+                        "tx_errors": 0,
+                        "tx_packets": 8
+                    },
+                    "eth5": {
+                        "rx_bytes": 4641,
+                        "rx_dropped": 0,
+                        "rx_errors": 0,
+                        "rx_packets": 26,
+                        "tx_bytes": 690,
+                        "tx_dropped": 0,
+                        "tx_errors": 0,
+                        "tx_packets": 9
+                    }
+                },
+                "memory_stats": {
+                    "stats": {
+                        "total_pgmajfault": 0,
+                        "cache": 0,
+                        "mapped_file": 0,
+                        "total_inactive_file": 0,
+                        "pgpgout": 414,
+                        "rss": 6537216,
+                        "total_mapped_file": 0,
+                        "writeback": 0,
+                        "unevictable": 0,
+                        "pgpgin": 477,
+                        "total_unevictable": 0,
+                        "pgmajfault": 0,
+                        "total_rss": 6537216,
+                        "total_rss_huge": 6291456,
+                        "total_writeback": 0,
+                        "total_inactive_anon": 0,
+                        "rss_huge": 6291456,
+                        "hierarchical_memory_limit": 67108864,
+                        "total_pgfault": 964,
+                        "total_active_file": 0,
+                        "active_anon": 6537216,
+                        "total_active_anon": 6537216,
+                        "total_pgpgout": 414,
+                        "total_cache": 0,
+                        "inactive_anon": 0,
+                        "active_file": 0,
+                        "pgfault": 964,
+                        "inactive_file": 0,
+                        "total_pgpgin": 477
+                    },
+                    "max_usage": 6651904,
+                    "usage": 6537216,
+                    "failcnt": 0,
+                    "limit": 67108864
+                },
+                "blkio_stats": {
+                    "io_service_bytes_recursive": [
+                        {
+                            "major": 8,
+                            "minor": 0,
+                            "op": "Read",
+                            "value": 1024
+                        },
+                        {
+                            "major": 8,
+                            "minor": 0,
+                            "op": "Write",
+                            "value": 2048
+                        }
+                    ],
+                    "io_serviced_recursive": null
+                },
+                "cpu_stats": {
+                    "cpu_usage": {
+                        "percpu_usage": [
+                            8646879,
+                            24472255,
+                            36438778,
+                            30657443
+                        ],
+                        "usage_in_usermode": 50000000,
+                        "total_usage": 100215355,
+                        "usage_in_kernelmode": 30000000
+                    },
+                    "system_cpu_usage": 739306590000000,
+                    "online_cpus": 4,
+                    "throttling_data": {
+                        "periods": 0,
+                        "throttled_periods": 0,
+                        "throttled_time": 0
+                    }
+                },
+                "precpu_stats": {
+                    "cpu_usage": {
+                        "percpu_usage": [
+                            8646879,
+                            24350896,
+                            36438778,
+                            30657443
+                        ],
+                        "usage_in_usermode": 50000000,
+                        "total_usage": 100093996,
+                        "usage_in_kernelmode": 30000000
+                    },
+                    "system_cpu_usage": 9492140000000,
+                    "online_cpus": 4,
+                    "throttling_data": {
+                        "periods": 0,
+                        "throttled_periods": 0,
+                        "throttled_time": 0
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.pids_stats.current, Some(3));
+        assert_eq!(response.memory_usage(), 6_537_216);
+        assert!((response.memory_percent() - 9.741_210_937_5).abs() < 1e-9);
+        assert_eq!(response.network_io(), (9979, 1338));
+        assert_eq!(response.block_io(), (1024, 2048));
+
+        let expected_cpu_percent =
+            (121_359.0 / (739_306_590_000_000.0 - 9_492_140_000_000.0)) * 4.0 * 100.0;
+        assert!((response.cpu_percent() - expected_cpu_percent).abs() < 1e-12);
+    }
+
+    #[test]
+    fn memory_usage_cgroups_v2() {
+        let memory_stats: MemoryStats = serde_json::from_str(
+            r#"{
+                "usage": 10000,
+                "limit": 20000,
+                "stats": {
+                    "inactive_file": 2000,
+                    "anon": 8000
+                }
+            }"#,
+        )
+        .unwrap();
+
+        let mut response: Response = serde_json::from_str(
+            r#"{
+                "read": "2015-01-08T22:57:31.547920715Z",
+                "preread": "0001-01-01T00:00:00Z",
+                "cpu_stats": {},
+                "precpu_stats": {}
+            }"#,
+        )
+        .unwrap();
+        response.memory_stats = memory_stats;
+
+        assert_eq!(response.memory_usage(), 8000);
+        assert!((response.memory_percent() - 40.0).abs() < std::f64::EPSILON);
+        assert!(response.cpu_percent().abs() < std::f64::EPSILON);
+    }
+}
@@ -0,0 +1,74 @@
+use crate::{containers::StateChange, http_client::HttpClient, Result};
+use serde::Serialize;
+use std::time::Duration;
+
+/// A request to stop a running docker container
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+/// use std::time::Duration;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let id = "CONTAINER_ID";
+///
+///     Docker::new()
+///         .containers()
+///         .stop(id)
+///         .timeout(Duration::from_secs(5))
+///         .send()
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Stop<'a> {
+    http_client: &'a HttpClient,
+    container: &'a str,
+    query: Query,
+}
+
+impl<'a> Stop<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, container: &'a str) -> Self {
+        let query = Query::default();
+        Self {
+            http_client,
+            container,
+            query,
+        }
+    }
+
+    /// The time to wait before killing the container.
+    ///
+    /// The duration is rounded down to the nearest second. If unset, the
+    /// container's configured stop timeout is used.
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.query.t = Some(timeout.as_secs());
+        self
+    }
+
+    /// Consume the request builder and send the request to the Docker host.
+    ///
+    /// Returns [`StateChange::Unchanged`] if the container was already
+    /// stopped.
+    pub async fn send(self) -> Result<StateChange> {
+        let endpoint = format!("/containers/{}/stop", self.container);
+        let status = self
+            .http_client
+            .post(endpoint)
+            .query(self.query)
+            .into_status()
+            .await?;
+
+        Ok(StateChange::from_status(status))
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Query {
+    t: Option<u64>,
+}
@@ -0,0 +1,122 @@
+use crate::{http_client::HttpClient, Result};
+use serde::{Deserialize, Serialize};
+
+/// A request to list the processes running inside a docker container
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let id = "CONTAINER_ID";
+///
+///     let response = Docker::new()
+///         .containers()
+///         .top(id)
+///         .ps_args("aux")
+///         .send()
+///         .await?;
+///
+///     println!("{}", response.titles.join("\t"));
+///     for process in response.processes {
+///         println!("{}", process.join("\t"));
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Top<'a> {
+    http_client: &'a HttpClient,
+    container: &'a str,
+    query: Query<'a>,
+}
+
+impl<'a> Top<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, container: &'a str) -> Self {
+        let query = Query::default();
+        Self {
+            http_client,
+            container,
+            query,
+        }
+    }
+
+    /// The arguments to pass to `ps`.
+    ///
+    /// default is '-ef'.
+    #[must_use]
+    pub fn ps_args(mut self, ps_args: &'a str) -> Self {
+        self.query.ps_args = Some(ps_args);
+        self
+    }
+
+    /// Consume the request builder and send the request to the Docker host
+    pub async fn send(self) -> Result<Response> {
+        let endpoint = format!("/containers/{}/top", self.container);
+        self.http_client
+            .get(endpoint)
+            .query(self.query)
+            .into_json()
+            .await
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Query<'a> {
+    ps_args: Option<&'a str>,
+}
+
+/// The processes running inside a container, as reported by `ps`
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Response {
+    /// The column headings of the `ps` output
+    pub titles: Vec<String>,
+
+    /// The rows of the `ps` output. Each row has one value for each of the
+    /// [`titles`](Response::titles)
+    pub processes: Vec<Vec<String>>,
+}
+
+impl Response {
+    /// Return the values of a column of the `ps` output, one for each process
+    ///
+    /// Returns `None` if there is no column with the given title.
+    #[must_use]
+    pub fn column(&self, title: &str) -> Option<Vec<&str>> {
+        let index = self.titles.iter().position(|t| t == title)?;
+
+        Some(
+            self.processes
+                .iter()
+                .filter_map(|process| process.get(index).map(String::as_str))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Response;
+
+    #[test]
+    fn deserialise_response() {
+        let response: Response = serde_json::from_str(
+            r#"{
+                "Titles": ["UID", "PID", "PPID", "C", "STIME", "TTY", "TIME", "CMD"],
+                "Processes": [
+                    ["root", "13642", "882", "0", "17:03", "pts/0", "00:00:00", "/bin/bash"],
+                    ["root", "13735", "13642", "0", "17:06", "pts/0", "00:00:00", "sleep 10"]
+                ]
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.titles.len(), 8);
+        assert_eq!(response.column("PID"), Some(vec!["13642", "13735"]));
+        assert_eq!(response.column("MISSING"), None);
+    }
+}
@@ -0,0 +1,760 @@
+use crate::utils::{
+    duration_nanos, empty_string_as_none, map_keys, null_as_default, parse_non_empty,
+};
+use serde::{Deserialize, Serialize};
+use std::{collections::HashMap, fmt, net::IpAddr, time::Duration};
+
+/// The outcome of a request to change the state of a container
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StateChange {
+    /// The state of the container was changed
+    Changed,
+
+    /// The container was already in the requested state, so nothing was done
+    Unchanged,
+}
+
+impl StateChange {
+    pub(crate) fn from_status(status: hyper::StatusCode) -> Self {
+        if status == hyper::StatusCode::NOT_MODIFIED {
+            Self::Unchanged
+        } else {
+            Self::Changed
+        }
+    }
+}
+
+/// The transport protocol of a container port
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum PortType {
+    /// Transmission Control Protocol
+    Tcp,
+
+    /// User Datagram Protocol
+    Udp,
+
+    /// Stream Control Transmission Protocol
+    Sctp,
+}
+
+impl fmt::Display for PortType {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Tcp => write!(f, "tcp"),
+            Self::Udp => write!(f, "udp"),
+            Self::Sctp => write!(f, "sctp"),
+        }
+    }
+}
+
+/// A binding between a container port and a port on the host
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct PortBinding {
+    /// The host IP address to bind to
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "empty_string_as_none"
+    )]
+    pub host_ip: Option<String>,
+
+    /// The host port to bind to.
+    ///
+    /// If unset, a port is chosen by the docker host.
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "empty_string_as_none"
+    )]
+    pub host_port: Option<String>,
+}
+
+/// The behaviour to apply when a container exits
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RestartPolicy {
+    /// The name of the restart policy
+    pub name: RestartPolicyName,
+
+    /// The number of times to retry before giving up.
+    ///
+    /// Only used with [`RestartPolicyName::OnFailure`].
+    #[serde(default)]
+    pub maximum_retry_count: u32,
+}
+
+impl RestartPolicy {
+    /// Never restart the container
+    #[must_use]
+    pub fn no() -> Self {
+        Self {
+            name: RestartPolicyName::No,
+            maximum_retry_count: 0,
+        }
+    }
+
+    /// Always restart the container
+    #[must_use]
+    pub fn always() -> Self {
+        Self {
+            name: RestartPolicyName::Always,
+            maximum_retry_count: 0,
+        }
+    }
+
+    /// Always restart the container, unless it has been manually stopped
+    #[must_use]
+    pub fn unless_stopped() -> Self {
+        Self {
+            name: RestartPolicyName::UnlessStopped,
+            maximum_retry_count: 0,
+        }
+    }
+
+    /// Restart the container if it exits with a non-zero exit code, at most
+    /// `maximum_retry_count` times
+    #[must_use]
+    pub fn on_failure(maximum_retry_count: u32) -> Self {
+        Self {
+            name: RestartPolicyName::OnFailure,
+            maximum_retry_count,
+        }
+    }
+}
+
+/// The name of a [`RestartPolicy`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum RestartPolicyName {
+    /// Never restart the container
+    #[serde(alias = "")]
+    No,
+
+    /// Always restart the container
+    Always,
+
+    /// Always restart the container, unless it has been manually stopped
+    UnlessStopped,
+
+    /// Restart the container if it exits with a non-zero exit code
+    OnFailure,
+}
+
+/// The type of a [`Mount`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum MountType {
+    /// A path on the host, mounted into the container
+    Bind,
+
+    /// A docker volume
+    Volume,
+
+    /// A tmpfs filesystem
+    Tmpfs,
+
+    /// A named pipe from the host, mounted into the container
+    Npipe,
+}
+
+/// A specification for mounting a filesystem into a container
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Mount {
+    /// The path inside the container at which to mount
+    pub target: String,
+
+    /// The source of the mount.
+    ///
+    /// This is a path on the host for bind mounts, and a volume name for
+    /// volume mounts.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub source: Option<String>,
+
+    /// The type of the mount
+    pub r#type: MountType,
+
+    /// Whether the mount is read-only
+    #[serde(default)]
+    pub read_only: bool,
+
+    /// Options for bind mounts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bind_options: Option<BindOptions>,
+
+    /// Options for volume mounts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub volume_options: Option<VolumeOptions>,
+
+    /// Options for tmpfs mounts
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub tmpfs_options: Option<TmpfsOptions>,
+}
+
+impl Mount {
+    /// Mount a path on the host into the container
+    pub fn bind(source: impl Into<String>, target: impl Into<String>) -> Self {
+        Self::new(MountType::Bind, Some(source.into()), target.into())
+    }
+
+    /// Mount a docker volume into the container.
+    ///
+    /// The volume is created if it doesn't already exist.
+    pub fn volume(name: impl Into<String>, target: impl Into<String>) -> Self {
+        Self::new(MountType::Volume, Some(name.into()), target.into())
+    }
+
+    /// Mount a tmpfs filesystem into the container
+    pub fn tmpfs(target: impl Into<String>) -> Self {
+        Self::new(MountType::Tmpfs, None, target.into())
+    }
+
+    fn new(r#type: MountType, source: Option<String>, target: String) -> Self {
+        Self {
+            target,
+            source,
+            r#type,
+            read_only: false,
+            bind_options: None,
+            volume_options: None,
+            tmpfs_options: None,
+        }
+    }
+
+    /// Set whether the mount is read-only.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn read_only(mut self, read_only: bool) -> Self {
+        self.read_only = read_only;
+        self
+    }
+}
+
+/// Options for bind mounts
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct BindOptions {
+    /// The propagation mode of the mount, such as `rprivate` or `shared`
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub propagation: Option<String>,
+}
+
+/// Options for volume mounts
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct VolumeOptions {
+    /// Don't populate the volume with data from the target path
+    #[serde(default)]
+    pub no_copy: bool,
+
+    /// User-defined metadata for the volume
+    #[serde(default, skip_serializing_if = "HashMap::is_empty")]
+    pub labels: HashMap<String, String>,
+}
+
+/// Options for tmpfs mounts
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct TmpfsOptions {
+    /// The size of the tmpfs mount in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub size_bytes: Option<i64>,
+
+    /// The permission mode of the tmpfs mount
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mode: Option<u32>,
+}
+
+/// A test to check that a container is healthy
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct HealthConfig {
+    /// The test to perform.
+    ///
+    /// This is one of:
+    /// - `[]` inherit the healthcheck from the image
+    /// - `["NONE"]` disable the healthcheck
+    /// - `["CMD", args...]` exec arguments directly
+    /// - `["CMD-SHELL", command]` run a command with the system's default
+    ///   shell
+    #[serde(default)]
+    pub test: Vec<String>,
+
+    /// The time to wait between checks
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "duration_nanos"
+    )]
+    pub interval: Option<Duration>,
+
+    /// The time to wait before considering the check to have hung
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "duration_nanos"
+    )]
+    pub timeout: Option<Duration>,
+
+    /// The number of consecutive failures needed to consider a container as
+    /// unhealthy
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub retries: Option<u32>,
+
+    /// The time to wait for the container to initialise before starting
+    /// health-retries countdown
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        with = "duration_nanos"
+    )]
+    pub start_period: Option<Duration>,
+}
+
+impl HealthConfig {
+    /// Check the health of the container by executing a command directly
+    pub fn cmd<S: Into<String>>(args: impl IntoIterator<Item = S>) -> Self {
+        let mut test = vec!["CMD".to_string()];
+        test.extend(args.into_iter().map(Into::into));
+        Self::new(test)
+    }
+
+    /// Check the health of the container by running a command with the
+    /// container's default shell
+    pub fn cmd_shell(command: impl Into<String>) -> Self {
+        Self::new(vec!["CMD-SHELL".to_string(), command.into()])
+    }
+
+    /// Disable any healthcheck inherited from the image
+    #[must_use]
+    pub fn none() -> Self {
+        Self::new(vec!["NONE".to_string()])
+    }
+
+    fn new(test: Vec<String>) -> Self {
+        Self {
+            test,
+            ..Self::default()
+        }
+    }
+
+    /// Set the time to wait between checks
+    #[must_use]
+    pub fn interval(mut self, interval: Duration) -> Self {
+        self.interval = Some(interval);
+        self
+    }
+
+    /// Set the time to wait before considering the check to have hung
+    #[must_use]
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = Some(timeout);
+        self
+    }
+
+    /// Set the number of consecutive failures needed to consider a container
+    /// as unhealthy
+    #[must_use]
+    pub fn retries(mut self, retries: u32) -> Self {
+        self.retries = Some(retries);
+        self
+    }
+
+    /// Set the time to wait for the container to initialise before starting
+    /// health-retries countdown
+    #[must_use]
+    pub fn start_period(mut self, start_period: Duration) -> Self {
+        self.start_period = Some(start_period);
+        self
+    }
+}
+
+/// The resource limits of a container
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Resources {
+    /// The relative CPU weight of the container, compared to other containers
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_shares: Option<i64>,
+
+    /// The memory limit in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory: Option<i64>,
+
+    /// The soft memory limit in bytes
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_reservation: Option<i64>,
+
+    /// The total memory limit (memory + swap) in bytes.
+    ///
+    /// Set to `-1` to enable unlimited swap.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub memory_swap: Option<i64>,
+
+    /// CPU quota in units of 10<sup>-9</sup> CPUs
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub nano_cpus: Option<i64>,
+
+    /// The length of a CPU period in microseconds
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_period: Option<i64>,
+
+    /// Microseconds of CPU time that the container can get in a CPU period
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub cpu_quota: Option<i64>,
+
+    /// The CPUs in which to allow execution (e.g. `0-3`, `0,1`)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "empty_string_as_none"
+    )]
+    pub cpuset_cpus: Option<String>,
+
+    /// The memory nodes in which to allow execution (e.g. `0-3`, `0,1`)
+    #[serde(
+        default,
+        skip_serializing_if = "Option::is_none",
+        deserialize_with = "empty_string_as_none"
+    )]
+    pub cpuset_mems: Option<String>,
+
+    /// The relative block IO weight of the container (10 to 1000)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blkio_weight: Option<u16>,
+
+    /// Per-device block IO weights
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blkio_weight_device: Option<Vec<WeightDevice>>,
+
+    /// Per-device limits on the read rate in bytes per second
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blkio_device_read_bps: Option<Vec<ThrottleDevice>>,
+
+    /// Per-device limits on the write rate in bytes per second
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub blkio_device_write_bps: Option<Vec<ThrottleDevice>>,
+
+    /// Per-device limits on the read rate in IO operations per second
+    #[serde(
+        default,
+        rename = "BlkioDeviceReadIOps",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub blkio_device_read_iops: Option<Vec<ThrottleDevice>>,
+
+    /// Per-device limits on the write rate in IO operations per second
+    #[serde(
+        default,
+        rename = "BlkioDeviceWriteIOps",
+        skip_serializing_if = "Option::is_none"
+    )]
+    pub blkio_device_write_iops: Option<Vec<ThrottleDevice>>,
+
+    /// The maximum number of processes in the container.
+    ///
+    /// Set to `0` or `-1` for unlimited.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub pids_limit: Option<i64>,
+
+    /// Disable the OOM killer for the container
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub oom_kill_disable: Option<bool>,
+}
+
+/// A relative block IO weight for a device
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct WeightDevice {
+    /// The path to the device
+    #[serde(default)]
+    pub path: String,
+
+    /// The relative weight of the device
+    #[serde(default)]
+    pub weight: u16,
+}
+
+/// A block IO rate limit for a device
+#[derive(Debug, Clone, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct ThrottleDevice {
+    /// The path to the device
+    #[serde(default)]
+    pub path: String,
+
+    /// The rate limit
+    #[serde(default)]
+    pub rate: u64,
+}
+
+/// The configuration of a container, which is independent of the host
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Config {
+    /// The hostname of the container
+    #[serde(default)]
+    pub hostname: String,
+
+    /// The domain name of the container
+    #[serde(default)]
+    pub domainname: String,
+
+    /// The user that commands are run as inside the container
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub user: Option<String>,
+
+    /// Whether stdin is attached
+    #[serde(default)]
+    pub attach_stdin: bool,
+
+    /// Whether stdout is attached
+    #[serde(default)]
+    pub attach_stdout: bool,
+
+    /// Whether stderr is attached
+    #[serde(default)]
+    pub attach_stderr: bool,
+
+    /// The ports exposed by the container, in the form `<port>/<protocol>`
+    #[serde(default, deserialize_with = "map_keys")]
+    pub exposed_ports: Vec<String>,
+
+    /// Whether a pseudo-TTY is allocated
+    #[serde(default)]
+    pub tty: bool,
+
+    /// Whether stdin is kept open, even if not attached
+    #[serde(default)]
+    pub open_stdin: bool,
+
+    /// Whether stdin is closed after the first attached client disconnects
+    #[serde(default)]
+    pub stdin_once: bool,
+
+    /// Environment variables, in the form `KEY=value`
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub env: Vec<String>,
+
+    /// The command run in the container
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub cmd: Vec<String>,
+
+    /// The test used to check that the container is healthy
+    #[serde(default)]
+    pub healthcheck: Option<HealthConfig>,
+
+    /// The name of the image the container was created from
+    #[serde(default)]
+    pub image: String,
+
+    /// The paths inside the container which are used as volumes
+    #[serde(default, deserialize_with = "map_keys")]
+    pub volumes: Vec<String>,
+
+    /// The working directory that commands run in
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub working_dir: Option<String>,
+
+    /// The entrypoint of the container
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub entrypoint: Vec<String>,
+
+    /// Whether networking is disabled for the container
+    #[serde(default)]
+    pub network_disabled: bool,
+
+    /// The MAC address of the container
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub mac_address: Option<String>,
+
+    /// `ONBUILD` metadata defined in the image's Dockerfile
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub on_build: Vec<String>,
+
+    /// User-defined key/value metadata
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub labels: HashMap<String, String>,
+
+    /// The signal used to stop the container
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub stop_signal: Option<String>,
+
+    /// The timeout to stop the container, in seconds
+    #[serde(default)]
+    pub stop_timeout: Option<u32>,
+
+    /// The shell used for the shell form of `RUN`, `CMD` and `ENTRYPOINT`
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub shell: Vec<String>,
+}
+
+/// The network settings of a container
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct NetworkSettings {
+    /// The name of the network's bridge
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub bridge: Option<String>,
+
+    /// The ID of the network sandbox
+    #[serde(
+        default,
+        rename = "SandboxID",
+        deserialize_with = "empty_string_as_none"
+    )]
+    pub sandbox_id: Option<String>,
+
+    /// The path of the network sandbox
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub sandbox_key: Option<String>,
+
+    /// Whether hairpin NAT is enabled
+    #[serde(default)]
+    pub hairpin_mode: bool,
+
+    /// The ports of the container, and the host ports they are bound to.
+    ///
+    /// Keys are of the form `<port>/<protocol>`. Exposed ports which are not
+    /// bound to the host have a value of `None`.
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub ports: HashMap<String, Option<Vec<PortBinding>>>,
+
+    /// The IP address of the container on the default bridge network
+    #[serde(default, rename = "IPAddress", deserialize_with = "parse_non_empty")]
+    pub ip_address: Option<IpAddr>,
+
+    /// The gateway of the default bridge network
+    #[serde(default, deserialize_with = "parse_non_empty")]
+    pub gateway: Option<IpAddr>,
+
+    /// The MAC address of the container on the default bridge network
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub mac_address: Option<String>,
+
+    /// The networks that the container is connected to, keyed by network name
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub networks: HashMap<String, EndpointSettings>,
+}
+
+/// The configuration of a container's connection to a network
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct EndpointSettings {
+    /// Links to other containers
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub links: Vec<String>,
+
+    /// Aliases of the container on the network
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub aliases: Vec<String>,
+
+    /// The unique ID of the network
+    #[serde(default, rename = "NetworkID")]
+    pub network_id: String,
+
+    /// The unique ID of the service endpoint
+    #[serde(default, rename = "EndpointID")]
+    pub endpoint_id: String,
+
+    /// The gateway address of the network
+    #[serde(default, deserialize_with = "parse_non_empty")]
+    pub gateway: Option<IpAddr>,
+
+    /// The IPv4 address of the container on the network
+    #[serde(default, rename = "IPAddress", deserialize_with = "parse_non_empty")]
+    pub ip_address: Option<IpAddr>,
+
+    /// The mask length of the IPv4 address
+    #[serde(default, rename = "IPPrefixLen")]
+    pub ip_prefix_len: u8,
+
+    /// The IPv6 gateway address of the network
+    #[serde(default, rename = "IPv6Gateway", deserialize_with = "parse_non_empty")]
+    pub ipv6_gateway: Option<IpAddr>,
+
+    /// The global IPv6 address of the container on the network
+    #[serde(
+        default,
+        rename = "GlobalIPv6Address",
+        deserialize_with = "parse_non_empty"
+    )]
+    pub global_ipv6_address: Option<IpAddr>,
+
+    /// The mask length of the global IPv6 address
+    #[serde(default, rename = "GlobalIPv6PrefixLen")]
+    pub global_ipv6_prefix_len: u8,
+
+    /// The MAC address of the container on the network
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub mac_address: Option<String>,
+}
+
+/// A filesystem mounted into a container
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct MountPoint {
+    /// The type of the mount
+    #[serde(default)]
+    pub r#type: Option<MountType>,
+
+    /// The name of the volume, for volume mounts
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub name: Option<String>,
+
+    /// The source of the mount on the host
+    pub source: String,
+
+    /// The path inside the container at which the mount is mounted
+    pub destination: String,
+
+    /// The volume driver, for volume mounts
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub driver: Option<String>,
+
+    /// Comma-separated mount options, such as `ro,Z`
+    #[serde(default)]
+    pub mode: String,
+
+    /// Whether the mount is writable
+    #[serde(rename = "RW")]
+    pub rw: bool,
+
+    /// The propagation mode of the mount
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub propagation: Option<String>,
+}
+
+/// The storage driver used for a container or image filesystem
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct GraphDriver {
+    /// The name of the storage driver
+    pub name: String,
+
+    /// Low-level, driver-specific details
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub data: HashMap<String, String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::StateChange;
+    use hyper::StatusCode;
+
+    #[test]
+    fn state_change_from_status() {
+        assert_eq!(
+            StateChange::from_status(StatusCode::NO_CONTENT),
+            StateChange::Changed
+        );
+        assert_eq!(
+            StateChange::from_status(StatusCode::NOT_MODIFIED),
+            StateChange::Unchanged
+        );
+    }
+}
@@ -0,0 +1,241 @@
+use crate::{
+    containers::{Resources, RestartPolicy, ThrottleDevice, WeightDevice},
+    http_client::HttpClient,
+    utils::null_as_default,
+    Result,
+};
+use serde::{Deserialize, Serialize};
+
+/// A request to change the resource limits of a docker container without
+/// restarting it
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let id = "CONTAINER_ID";
+///
+///     let response = Docker::new()
+///         .containers()
+///         .update(id)
+///         .cpu_quota(50_000)
+///         .memory(128 * 1024 * 1024)
+///         .pids_limit(64)
+///         .send()
+///         .await?;
+///
+///     for warning in response.warnings {
+///         eprintln!("{}", warning);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Update<'a> {
+    http_client: &'a HttpClient,
+    container: &'a str,
+    body: Body,
+}
+
+impl<'a> Update<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, container: &'a str) -> Self {
+        let body = Body::default();
+        Self {
+            http_client,
+            container,
+            body,
+        }
+    }
+
+    /// Set the relative CPU weight of the container, compared to other
+    /// containers
+    #[must_use]
+    pub fn cpu_shares(mut self, cpu_shares: i64) -> Self {
+        self.body.resources.cpu_shares = Some(cpu_shares);
+        self
+    }
+
+    /// Set the length of a CPU period in microseconds
+    #[must_use]
+    pub fn cpu_period(mut self, cpu_period: i64) -> Self {
+        self.body.resources.cpu_period = Some(cpu_period);
+        self
+    }
+
+    /// Set the microseconds of CPU time that the container can get in a CPU
+    /// period
+    #[must_use]
+    pub fn cpu_quota(mut self, cpu_quota: i64) -> Self {
+        self.body.resources.cpu_quota = Some(cpu_quota);
+        self
+    }
+
+    /// Set the CPU quota of the container in units of 10<sup>-9</sup> CPUs
+    #[must_use]
+    pub fn nano_cpus(mut self, nano_cpus: i64) -> Self {
+        self.body.resources.nano_cpus = Some(nano_cpus);
+        self
+    }
+
+    /// Set the CPUs in which to allow execution (e.g. `0-3`, `0,1`)
+    #[must_use]
+    pub fn cpuset_cpus(mut self, cpuset_cpus: impl Into<String>) -> Self {
+        self.body.resources.cpuset_cpus = Some(cpuset_cpus.into());
+        self
+    }
+
+    /// Set the memory limit of the container, in bytes
+    #[must_use]
+    pub fn memory(mut self, memory: i64) -> Self {
+        self.body.resources.memory = Some(memory);
+        self
+    }
+
+    /// Set the soft memory limit of the container, in bytes
+    #[must_use]
+    pub fn memory_reservation(mut self, memory_reservation: i64) -> Self {
+        self.body.resources.memory_reservation = Some(memory_reservation);
+        self
+    }
+
+    /// Set the total memory limit (memory + swap) of the container, in bytes.
+    ///
+    /// Set to `-1` to enable unlimited swap.
+    #[must_use]
+    pub fn memory_swap(mut self, memory_swap: i64) -> Self {
+        self.body.resources.memory_swap = Some(memory_swap);
+        self
+    }
+
+    /// Set the maximum number of processes in the container.
+    ///
+    /// Set to `0` or `-1` for unlimited.
+    #[must_use]
+    pub fn pids_limit(mut self, pids_limit: i64) -> Self {
+        self.body.resources.pids_limit = Some(pids_limit);
+        self
+    }
+
+    /// Set the relative block IO weight of the container (10 to 1000)
+    #[must_use]
+    pub fn blkio_weight(mut self, blkio_weight: u16) -> Self {
+        self.body.resources.blkio_weight = Some(blkio_weight);
+        self
+    }
+
+    /// Set the relative block IO weight of a device
+    #[must_use]
+    pub fn blkio_weight_device(mut self, weight_device: WeightDevice) -> Self {
+        self.body
+            .resources
+            .blkio_weight_device
+            .get_or_insert_with(Vec::new)
+            .push(weight_device);
+        self
+    }
+
+    /// Limit the read rate from a device, in bytes per second
+    #[must_use]
+    pub fn blkio_device_read_bps(mut self, throttle_device: ThrottleDevice) -> Self {
+        self.body
+            .resources
+            .blkio_device_read_bps
+            .get_or_insert_with(Vec::new)
+            .push(throttle_device);
+        self
+    }
+
+    /// Limit the write rate to a device, in bytes per second
+    #[must_use]
+    pub fn blkio_device_write_bps(mut self, throttle_device: ThrottleDevice) -> Self {
+        self.body
+            .resources
+            .blkio_device_write_bps
+            .get_or_insert_with(Vec::new)
+            .push(throttle_device);
+        self
+    }
+
+    /// Set the resource limits of the container.
+    ///
+    /// This replaces any limits set by other methods. Limits which are not set
+    /// are left unchanged.
+    #[must_use]
+    pub fn resources(mut self, resources: Resources) -> Self {
+        self.body.resources = resources;
+        self
+    }
+
+    /// The behaviour to apply when the container exits
+    #[must_use]
+    pub fn restart_policy(mut self, restart_policy: RestartPolicy) -> Self {
+        self.body.restart_policy = Some(restart_policy);
+        self
+    }
+
+    /// Consume the request builder and send the request to the Docker host
+    pub async fn send(self) -> Result<Response> {
+        let endpoint = format!("/containers/{}/update", self.container);
+        self.http_client
+            .post(endpoint)
+            .json_body(self.body)
+            .into_json()
+            .await
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Body {
+    #[serde(flatten)]
+    resources: Resources,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    restart_policy: Option<RestartPolicy>,
+}
+
+/// The response from updating a container
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Response {
+    /// Warnings encountered while updating the container
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub warnings: Vec<String>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Body, Response};
+    use crate::containers::RestartPolicy;
+
+    #[test]
+    fn serialize_body() {
+        let mut body = Body::default();
+        body.resources.cpu_quota = Some(50_000);
+        body.resources.pids_limit = Some(64);
+        body.restart_policy = Some(RestartPolicy::unless_stopped());
+
+        let json = serde_json::to_value(&body).unwrap();
+
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "CpuQuota": 50_000,
+                "PidsLimit": 64,
+                "RestartPolicy": {
+                    "Name": "unless-stopped",
+                    "MaximumRetryCount": 0
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn deserialize_response() {
+        let response: Response = serde_json::from_str(r#"{"Warnings": null}"#).unwrap();
+        assert!(response.warnings.is_empty());
+    }
+}
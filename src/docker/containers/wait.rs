@@ -0,0 +1,143 @@
+use crate::{http_client::HttpClient, Result};
+use serde::{Deserialize, Serialize};
+
+/// A request to block until a docker container stops, and return its exit
+/// code
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{containers::Condition, Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let id = "CONTAINER_ID";
+///
+///     let response = Docker::new()
+///         .containers()
+///         .wait(id)
+///         .condition(Condition::NextExit)
+///         .send()
+///         .await?;
+///
+///     println!("exited with status code {}", response.status_code);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Wait<'a> {
+    http_client: &'a HttpClient,
+    container: &'a str,
+    query: Query,
+}
+
+impl<'a> Wait<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, container: &'a str) -> Self {
+        let query = Query::default();
+        Self {
+            http_client,
+            container,
+            query,
+        }
+    }
+
+    /// The condition to wait for.
+    ///
+    /// default is [`Condition::NotRunning`].
+    #[must_use]
+    pub fn condition(mut self, condition: Condition) -> Self {
+        self.query.condition = Some(condition);
+        self
+    }
+
+    /// Consume the request builder and return a future that resolves when the
+    /// container meets the wait condition
+    pub async fn send(self) -> Result<Response> {
+        let endpoint = format!("/containers/{}/wait", self.container);
+        self.http_client
+            .post(endpoint)
+            .query(self.query)
+            .into_json()
+            .await
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Query {
+    condition: Option<Condition>,
+}
+
+/// The condition to wait for when waiting for a container
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "kebab-case")]
+pub enum Condition {
+    /// Wait until the container is not running
+    NotRunning,
+
+    /// Wait until the container next exits
+    NextExit,
+
+    /// Wait until the container has been removed
+    Removed,
+}
+
+/// Response returned when a container meets a wait condition
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Response {
+    /// The exit code of the container
+    pub status_code: i64,
+
+    /// An error which occurred while waiting for the container
+    #[serde(default)]
+    pub error: Option<WaitError>,
+}
+
+/// An error which occurred while waiting for a container
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct WaitError {
+    /// Details of the error
+    pub message: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialise_query() {
+        let query = Query {
+            condition: Some(Condition::NextExit),
+        };
+
+        assert_eq!(
+            serde_urlencoded::to_string(query).unwrap(),
+            "condition=next-exit"
+        );
+    }
+
+    #[test]
+    fn deserialise_response() {
+        let response: Response = serde_json::from_str(
+            r#"{
+                "StatusCode": 9,
+                "Error": {
+                    "Message": "container not found"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.status_code, 9);
+        assert_eq!(response.error.unwrap().message, "container not found");
+    }
+
+    #[test]
+    fn deserialise_response_without_error() {
+        let response: Response = serde_json::from_str(r#"{"StatusCode": 0}"#).unwrap();
+
+        assert!(response.error.is_none());
+    }
+}
@@ -0,0 +1,60 @@
+//! Endpoints and objects for running commands inside Docker containers
+
+use crate::http_client::HttpClient;
+use std::sync::Arc;
+
+mod create;
+pub use create::{Create, Output, Response as CreateResponse};
+
+mod start;
+pub use start::Start;
+
+mod inspect;
+pub use inspect::{Inspect, ProcessConfig, Response as InspectResponse};
+
+pub use crate::containers::Resize;
+
+/// A client to the 'exec' subset of Docker API endpoints
+#[derive(Debug)]
+pub struct Exec {
+    http_client: Arc<HttpClient>,
+}
+
+impl Exec {
+    pub(crate) fn new(http_client: Arc<HttpClient>) -> Self {
+        Self { http_client }
+    }
+
+    /// Create a new exec instance, which runs a command inside a running
+    /// container.
+    ///
+    /// The 'container' parameter may be a name or an id.
+    #[must_use]
+    pub fn create<'a>(
+        &'a self,
+        container: &'a str,
+        cmd: impl IntoIterator<Item = &'a str>,
+    ) -> Create<'a> {
+        Create::new(&self.http_client, container, cmd)
+    }
+
+    /// Start a previously created exec instance
+    #[must_use]
+    pub fn start<'a>(&'a self, id: &'a str) -> Start<'a> {
+        Start::new(&self.http_client, id)
+    }
+
+    /// Retrieve details about an exec instance
+    #[must_use]
+    pub fn inspect<'a>(&'a self, id: &'a str) -> Inspect<'a> {
+        Inspect::new(&self.http_client, id)
+    }
+
+    /// Resize the TTY of an exec instance.
+    ///
+    /// This only has an effect if the exec instance was created with a TTY.
+    #[must_use]
+    pub fn resize<'a>(&'a self, id: &'a str, height: u16, width: u16) -> Resize<'a> {
+        Resize::exec(&self.http_client, id, height, width)
+    }
+}
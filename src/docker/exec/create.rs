@@ -0,0 +1,334 @@
+use crate::{
+    containers::LogOutput,
+    exec::{Inspect, Start},
+    http_client::HttpClient,
+    Error, Result,
+};
+use futures_util::stream::TryStreamExt;
+use serde::{Deserialize, Serialize};
+use std::time::Duration;
+
+/// How many times to inspect an exec instance which is still reported as
+/// running after its output has ended
+const EXIT_CODE_ATTEMPTS: u32 = 20;
+
+/// How long to wait between inspecting an exec instance for its exit code
+const EXIT_CODE_INTERVAL: Duration = Duration::from_millis(50);
+
+/// A request to create a new exec instance in a running container
+///
+/// # Examples
+///
+/// ## Run to completion
+///
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let id = "CONTAINER_ID";
+///
+///     let output = Docker::new()
+///         .exec()
+///         .create(id, vec!["ls", "-l"])
+///         .working_dir("/tmp")
+///         .output()
+///         .await?;
+///
+///     println!("exited with code {}", output.exit_code);
+///     println!("{}", String::from_utf8_lossy(&output.stdout));
+///
+///     Ok(())
+/// }
+/// ```
+///
+/// ## Create and start separately
+///
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let id = "CONTAINER_ID";
+///     let exec = Docker::new().exec();
+///
+///     let exec_id = exec
+///         .create(id, vec!["touch", "/tmp/file"])
+///         .env("KEY", "value")
+///         .send()
+///         .await?
+///         .id;
+///
+///     exec.start(&exec_id).send().await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Create<'a> {
+    http_client: &'a HttpClient,
+    container: &'a str,
+    body: Body<'a>,
+}
+
+impl<'a> Create<'a> {
+    pub(crate) fn new(
+        http_client: &'a HttpClient,
+        container: &'a str,
+        cmd: impl IntoIterator<Item = &'a str>,
+    ) -> Self {
+        let body = Body::new(cmd.into_iter().collect());
+        Self {
+            http_client,
+            container,
+            body,
+        }
+    }
+
+    /// Attach to stdin of the exec command.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn attach_stdin(mut self, attach_stdin: bool) -> Self {
+        self.body.attach_stdin = attach_stdin;
+        self
+    }
+
+    /// Attach to stdout of the exec command.
+    ///
+    /// default is 'true'.
+    #[must_use]
+    pub fn attach_stdout(mut self, attach_stdout: bool) -> Self {
+        self.body.attach_stdout = attach_stdout;
+        self
+    }
+
+    /// Attach to stderr of the exec command.
+    ///
+    /// default is 'true'.
+    #[must_use]
+    pub fn attach_stderr(mut self, attach_stderr: bool) -> Self {
+        self.body.attach_stderr = attach_stderr;
+        self
+    }
+
+    /// Override the key sequence for detaching from the exec command.
+    ///
+    /// Format is a single character `[a-Z]` or `ctrl-<value>` where `<value>`
+    /// is one of: `a-z`, `@`, `^`, `[`, `,` or `_`.
+    #[must_use]
+    pub fn detach_keys(mut self, detach_keys: &'a str) -> Self {
+        self.body.detach_keys = Some(detach_keys);
+        self
+    }
+
+    /// Allocate a pseudo-TTY.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn tty(mut self, tty: bool) -> Self {
+        self.body.tty = tty;
+        self
+    }
+
+    /// Set an environment variable for the exec command
+    #[must_use]
+    pub fn env(mut self, key: &str, value: &str) -> Self {
+        self.body.env.push(format!("{}={}", key, value));
+        self
+    }
+
+    /// Run the command with extended privileges.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn privileged(mut self, privileged: bool) -> Self {
+        self.body.privileged = privileged;
+        self
+    }
+
+    /// The user, and optionally the group, to run the command as.
+    ///
+    /// Format is one of: `user`, `user:group`, `uid`, or `uid:gid`.
+    #[must_use]
+    pub fn user(mut self, user: &'a str) -> Self {
+        self.body.user = Some(user);
+        self
+    }
+
+    /// The working directory for the command to run in
+    #[must_use]
+    pub fn working_dir(mut self, working_dir: &'a str) -> Self {
+        self.body.working_dir = Some(working_dir);
+        self
+    }
+
+    /// Consume the request builder and return a [`Response`]
+    pub async fn send(self) -> Result<Response> {
+        let endpoint = format!("/containers/{}/exec", self.container);
+        self.http_client
+            .post(endpoint)
+            .json_body(self.body)
+            .into_json()
+            .await
+    }
+
+    /// Create the exec instance, run it to completion and return its
+    /// [`Output`].
+    ///
+    /// stdout and stderr are always attached, and stdin is never attached.
+    pub async fn output(mut self) -> Result<Output> {
+        let http_client = self.http_client;
+
+        self.body.attach_stdin = false;
+        self.body.attach_stdout = true;
+        self.body.attach_stderr = true;
+        let tty = self.body.tty;
+
+        let id = self.send().await?.id;
+
+        let (stdout, stderr) = Start::new(http_client, &id)
+            .tty(tty)
+            .stream()
+            .try_fold(
+                (Vec::new(), Vec::new()),
+                |(mut stdout, mut stderr), output| async move {
+                    match output {
+                        LogOutput::StdErr(bytes) => stderr.extend_from_slice(&bytes),
+                        output => stdout.extend_from_slice(output.as_ref()),
+                    }
+                    Ok((stdout, stderr))
+                },
+            )
+            .await?;
+
+        let exit_code = exit_code(http_client, &id).await?;
+
+        Ok(Output {
+            exit_code,
+            stdout,
+            stderr,
+        })
+    }
+}
+
+/// Inspect a finished exec instance for its exit code.
+///
+/// The Docker host may briefly report the instance as running after its
+/// output has ended, so it is inspected again until it has stopped.
+async fn exit_code(http_client: &HttpClient, id: &str) -> Result<i64> {
+    let mut attempts = 1;
+
+    loop {
+        let response = Inspect::new(http_client, id).send().await?;
+
+        match response.exit_code {
+            Some(exit_code) if !response.running => return Ok(exit_code),
+            _ if response.running && attempts < EXIT_CODE_ATTEMPTS => {
+                attempts += 1;
+                tokio::time::delay_for(EXIT_CODE_INTERVAL).await;
+            }
+            exit_code => {
+                return exit_code
+                    .ok_or_else(|| Error::InvalidResponse("exec instance has no exit code".into()))
+            }
+        }
+    }
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "PascalCase")]
+#[allow(clippy::struct_excessive_bools)]
+struct Body<'a> {
+    attach_stdin: bool,
+    attach_stdout: bool,
+    attach_stderr: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    detach_keys: Option<&'a str>,
+    tty: bool,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    env: Vec<String>,
+    cmd: Vec<&'a str>,
+    privileged: bool,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    user: Option<&'a str>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    working_dir: Option<&'a str>,
+}
+
+impl<'a> Body<'a> {
+    fn new(cmd: Vec<&'a str>) -> Self {
+        Self {
+            attach_stdin: false,
+            attach_stdout: true,
+            attach_stderr: true,
+            detach_keys: None,
+            tty: false,
+            env: Vec::default(),
+            cmd,
+            privileged: false,
+            user: None,
+            working_dir: None,
+        }
+    }
+}
+
+/// Response returned when creating a new exec instance
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Response {
+    /// The ID of the exec instance
+    pub id: String,
+}
+
+/// The output of an exec instance which has run to completion
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Output {
+    /// The exit code of the command
+    pub exit_code: i64,
+
+    /// The data that the command wrote to stdout.
+    ///
+    /// If the exec instance has a TTY attached, this contains all of the
+    /// console output.
+    pub stdout: Vec<u8>,
+
+    /// The data that the command wrote to stderr
+    pub stderr: Vec<u8>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn serialise_body() {
+        let mut body = Body::new(vec!["ls", "-l"]);
+        body.env.push("KEY=value".to_string());
+        body.working_dir = Some("/tmp");
+
+        assert_eq!(
+            serde_json::to_value(body).unwrap(),
+            serde_json::json!({
+                "AttachStdin": false,
+                "AttachStdout": true,
+                "AttachStderr": true,
+                "Tty": false,
+                "Env": ["KEY=value"],
+                "Cmd": ["ls", "-l"],
+                "Privileged": false,
+                "WorkingDir": "/tmp"
+            })
+        );
+    }
+
+    #[test]
+    fn deserialise_response() {
+        let _: Response = serde_json::from_str(
+            r#"{
+                "Id": "f90e34656806"
+            }"#,
+        )
+        .unwrap();
+    }
+}
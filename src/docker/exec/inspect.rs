@@ -0,0 +1,142 @@
+use crate::{http_client::HttpClient, Result};
+use serde::Deserialize;
+
+/// A request to retrieve details about an exec instance
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let id = "EXEC_ID";
+///
+///     let response = Docker::new()
+///         .exec()
+///         .inspect(id)
+///         .send()
+///         .await?;
+///
+///     println!("{:#?}", response);
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Inspect<'a> {
+    http_client: &'a HttpClient,
+    id: &'a str,
+}
+
+impl<'a> Inspect<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, id: &'a str) -> Self {
+        Self { http_client, id }
+    }
+
+    /// Consume the request and return details about the exec instance
+    pub async fn send(self) -> Result<Response> {
+        let endpoint = format!("/exec/{}/json", self.id);
+        self.http_client.get(endpoint).into_json().await
+    }
+}
+
+/// A struct representation of the information returned by an 'exec inspect'
+/// command
+#[allow(clippy::struct_excessive_bools)]
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Response {
+    /// Whether the exec instance can be removed
+    pub can_remove: bool,
+
+    /// The ID of the container in which the command is run
+    #[serde(rename = "ContainerID")]
+    pub container_id: String,
+
+    /// The key sequence for detaching from the exec command
+    pub detach_keys: String,
+
+    /// The exit code of the command, if it has finished
+    pub exit_code: Option<i64>,
+
+    /// The unique ID of the exec instance
+    #[serde(rename = "ID")]
+    pub id: String,
+
+    /// Whether stderr is attached
+    pub open_stderr: bool,
+
+    /// Whether stdin is attached
+    pub open_stdin: bool,
+
+    /// Whether stdout is attached
+    pub open_stdout: bool,
+
+    /// Details of the command being run
+    pub process_config: ProcessConfig,
+
+    /// True if the command is still running
+    pub running: bool,
+
+    /// The PID of the command process on the host
+    pub pid: u32,
+}
+
+/// Details of the command run by an exec instance
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ProcessConfig {
+    /// The arguments passed to the entrypoint
+    #[serde(default)]
+    pub arguments: Vec<String>,
+
+    /// The command which is run
+    pub entrypoint: String,
+
+    /// Whether the command is run with extended privileges
+    pub privileged: bool,
+
+    /// Whether a pseudo-TTY is allocated
+    pub tty: bool,
+
+    /// The user which the command is run as
+    #[serde(default)]
+    pub user: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn deserialise_response() {
+        let response: Response = serde_json::from_str(
+            r#"{
+                "CanRemove": false,
+                "ContainerID": "b53ee82b53a40c7dca428523e34f741f3abc51d9f297a14ff874bf761b995126",
+                "DetachKeys": "",
+                "ExitCode": 2,
+                "ID": "f33bbfb39f5b142420f4759b2348913bd4a8d1a6d7fd56499cb41a1bb91d7b3b",
+                "OpenStderr": true,
+                "OpenStdin": true,
+                "OpenStdout": true,
+                "ProcessConfig": {
+                    "arguments": [
+                        "-c",
+                        "exit 2"
+                    ],
+                    "entrypoint": "sh",
+                    "privileged": false,
+                    "tty": true,
+                    "user": "1000"
+                },
+                "Running": false,
+                "Pid": 42000
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.exit_code, Some(2));
+    }
+}
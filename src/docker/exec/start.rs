@@ -0,0 +1,113 @@
+use crate::{
+    containers::{Attached, LogDecoder, LogOutput, ResizeHandle},
+    http_client::HttpClient,
+    Result,
+};
+use futures_util::stream::Stream;
+use serde::Serialize;
+
+/// A request to start a previously created exec instance
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+/// use tokio::stream::StreamExt;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let exec = Docker::new().exec();
+///
+///     let id = exec
+///         .create("CONTAINER_ID", vec!["ls", "-l"])
+///         .send()
+///         .await?
+///         .id;
+///
+///     let mut output = Box::pin(exec.start(&id).stream());
+///
+///     while let Some(frame) = output.next().await {
+///         print!("{}", frame?);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Start<'a> {
+    http_client: &'a HttpClient,
+    id: &'a str,
+    body: Body,
+}
+
+impl<'a> Start<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, id: &'a str) -> Self {
+        let body = Body::default();
+        Self {
+            http_client,
+            id,
+            body,
+        }
+    }
+
+    /// Allocate a pseudo-TTY.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn tty(mut self, tty: bool) -> Self {
+        self.body.tty = tty;
+        self
+    }
+
+    /// Consume the request and start the exec instance in the background.
+    pub async fn send(mut self) -> Result<()> {
+        self.body.detach = true;
+
+        let endpoint = format!("/exec/{}/start", self.id);
+        self.http_client
+            .post(endpoint)
+            .json_body(self.body)
+            .into_status()
+            .await?;
+
+        Ok(())
+    }
+
+    /// Consume the request, start the exec instance, and return a stream of
+    /// its output.
+    pub fn stream(self) -> impl Stream<Item = Result<LogOutput>> + 'a {
+        let endpoint = format!("/exec/{}/start", self.id);
+        let decoder = LogDecoder::new(Some(self.body.tty));
+        self.http_client
+            .post(endpoint)
+            .json_body(self.body)
+            .decode(decoder)
+    }
+
+    /// Consume the request, start the exec instance, and return an interactive
+    /// [`Attached`] session.
+    ///
+    /// To write to the command's stdin, the exec instance must have been
+    /// created with `attach_stdin` set.
+    pub async fn attach(self) -> Result<Attached<'a>> {
+        let endpoint = format!("/exec/{}/start", self.id);
+        let tty = self.body.tty;
+        let upgraded = self
+            .http_client
+            .post(endpoint)
+            .json_body(self.body)
+            .upgrade()
+            .await?;
+
+        let resize_handle = ResizeHandle::exec(self.http_client, self.id);
+
+        Ok(Attached::new(upgraded, resize_handle, Some(tty)))
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+#[serde(rename_all = "PascalCase")]
+struct Body {
+    detach: bool,
+    tty: bool,
+}
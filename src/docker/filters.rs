@@ -0,0 +1,208 @@
+use crate::containers::{HealthStatus, Status};
+use chrono::{DateTime, Utc};
+use serde::{Serialize, Serializer};
+use std::collections::BTreeMap;
+
+/// A set of filters for narrowing the results of 'list' endpoints
+///
+/// Not every filter is supported by every endpoint. Filters with the same key
+/// are combined with a logical 'OR', filters with different keys are combined
+/// with a logical 'AND'.
+///
+/// # Example
+/// ```no_run
+/// use longshoreman::{containers::Status, Docker, Filters, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let filters = Filters::new()
+///         .label("com.example.vendor", "Acme")
+///         .status(Status::Running)
+///         .status(Status::Paused);
+///
+///     let containers = Docker::new()
+///         .containers()
+///         .list()
+///         .filters(filters)
+///         .send()
+///         .await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Filters {
+    filters: BTreeMap<String, Vec<String>>,
+}
+
+impl Filters {
+    /// Create an empty set of filters
+    #[must_use]
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns true if no filters have been set
+    #[must_use]
+    pub fn is_empty(&self) -> bool {
+        self.filters.is_empty()
+    }
+
+    /// Add an arbitrary filter.
+    ///
+    /// This can be used for filters which don't have a dedicated method.
+    #[must_use]
+    pub fn add(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.filters
+            .entry(key.into())
+            .or_default()
+            .push(value.into());
+        self
+    }
+
+    /// Match objects with a label with the given key and value
+    #[must_use]
+    pub fn label(self, key: &str, value: &str) -> Self {
+        self.add("label", format!("{}={}", key, value))
+    }
+
+    /// Match objects with a label with the given key, regardless of its value
+    #[must_use]
+    pub fn label_exists(self, key: &str) -> Self {
+        self.add("label", key)
+    }
+
+    /// Match objects without a label with the given key and value
+    #[must_use]
+    pub fn label_not(self, key: &str, value: &str) -> Self {
+        self.add("label!", format!("{}={}", key, value))
+    }
+
+    /// Match containers with the given status
+    #[must_use]
+    pub fn status(self, status: Status) -> Self {
+        self.add("status", status.to_string())
+    }
+
+    /// Match objects by name
+    #[must_use]
+    pub fn name(self, name: &str) -> Self {
+        self.add("name", name)
+    }
+
+    /// Match objects by ID
+    #[must_use]
+    pub fn id(self, id: &str) -> Self {
+        self.add("id", id)
+    }
+
+    /// Match containers created from the given image, or a descendant of it.
+    ///
+    /// The image may be given as `<image-name>[:<tag>]`, `<image id>`, or
+    /// `<image@digest>`.
+    #[must_use]
+    pub fn ancestor(self, image: &str) -> Self {
+        self.add("ancestor", image)
+    }
+
+    /// Match objects created before the given object.
+    ///
+    /// The object may be given as a name or an ID.
+    #[must_use]
+    pub fn before(self, object: &str) -> Self {
+        self.add("before", object)
+    }
+
+    /// Match objects created since the given object.
+    ///
+    /// The object may be given as a name or an ID.
+    #[must_use]
+    pub fn since(self, object: &str) -> Self {
+        self.add("since", object)
+    }
+
+    /// Match containers connected to the given network
+    #[must_use]
+    pub fn network(self, network: &str) -> Self {
+        self.add("network", network)
+    }
+
+    /// Match containers which mount the given volume or path
+    #[must_use]
+    pub fn volume(self, volume: &str) -> Self {
+        self.add("volume", volume)
+    }
+
+    /// Match containers with the given health status
+    #[must_use]
+    pub fn health(self, health: HealthStatus) -> Self {
+        self.add("health", health.to_string())
+    }
+
+    /// Match containers which exited with the given exit code
+    #[must_use]
+    pub fn exited(self, exit_code: i32) -> Self {
+        self.add("exited", exit_code.to_string())
+    }
+
+    /// Match dangling images or volumes (those which are unused)
+    #[must_use]
+    pub fn dangling(self, dangling: bool) -> Self {
+        self.add("dangling", dangling.to_string())
+    }
+
+    /// Match images by reference, such as `ubuntu` or `ubuntu:18.04`
+    #[must_use]
+    pub fn reference(self, reference: &str) -> Self {
+        self.add("reference", reference)
+    }
+
+    /// Match volumes or networks by driver
+    #[must_use]
+    pub fn driver(self, driver: &str) -> Self {
+        self.add("driver", driver)
+    }
+
+    /// Match objects created before the given time.
+    ///
+    /// This is only supported by 'prune' endpoints.
+    #[must_use]
+    pub fn until(self, time: DateTime<Utc>) -> Self {
+        self.add("until", time.timestamp().to_string())
+    }
+}
+
+/// The Docker API expects filters to be a JSON-encoded string
+impl Serialize for Filters {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let json = serde_json::to_string(&self.filters).map_err(serde::ser::Error::custom)?;
+        serializer.serialize_str(&json)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Serialize)]
+    struct Query {
+        filters: Filters,
+    }
+
+    #[test]
+    fn serialise() {
+        let filters = Filters::new()
+            .label("key", "value")
+            .label_exists("other-key")
+            .status(Status::Running)
+            .health(HealthStatus::Healthy)
+            .exited(0);
+
+        let query = serde_urlencoded::to_string(Query { filters }).unwrap();
+
+        let expected_json = r#"{"exited":["0"],"health":["healthy"],"label":["key=value","other-key"],"status":["running"]}"#;
+        let expected = serde_urlencoded::to_string([("filters", expected_json)]).unwrap();
+
+        assert_eq!(query, expected);
+    }
+}
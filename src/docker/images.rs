@@ -1,7 +1,9 @@
 //! Endpoints and objects for juggling Docker images
 
 use crate::http_client::HttpClient;
-use std::sync::Arc;
+use futures_util::stream::Stream;
+use hyper::body::Bytes;
+use std::{io, sync::Arc};
 use tokio::io::AsyncRead;
 
 mod load;
@@ -10,6 +12,44 @@ pub use load::Load;
 mod pull;
 pub use pull::Pull;
 
+mod progress;
+pub use progress::{Progress, ProgressDetail, StatusMessage};
+
+mod list;
+pub use list::{List, Response as ListResponse};
+
+mod inspect;
+pub use inspect::{Inspect, Metadata, Response as InspectResponse, RootFs};
+
+mod history;
+pub use history::{History, Response as HistoryResponse};
+
+mod remove;
+pub use remove::{Remove, Response as RemoveResponse};
+
+mod auth;
+pub use auth::RegistryAuth;
+
+mod build;
+pub use build::{Build, BuildMessage};
+
+mod context;
+pub use context::BuildContext;
+
+mod dockerignore;
+
+mod credentials;
+pub use credentials::{registry_hostname, DockerConfig};
+
+mod tag;
+pub use tag::Tag;
+
+mod push;
+pub use push::Push;
+
+mod save;
+pub use save::Save;
+
 /// A client to the 'images' subset of Docker API endpoints
 #[derive(Debug)]
 pub struct Images {
@@ -47,7 +87,7 @@ impl Images {
     /// # Ok(())
     /// # }
     /// ```
-    pub fn load<'a>(&'a self, tar_archive: impl AsyncRead + 'a) -> Load<'a> {
+    pub fn load(&self, tar_archive: impl AsyncRead + Send + 'static) -> Load<'_> {
         Load::new(&self.http_client, tar_archive)
     }
 
@@ -56,4 +96,71 @@ impl Images {
     pub fn pull<'a>(&'a self, name: &'a str) -> Pull<'a> {
         Pull::new(&self.http_client, name)
     }
+
+    /// List local images
+    #[must_use]
+    pub fn list(&self) -> List<'_> {
+        List::new(&self.http_client)
+    }
+
+    /// Retrieve low-level information about an image
+    ///
+    /// The 'name' parameter may be a name, a name and tag, or an id.
+    #[must_use]
+    pub fn inspect<'a>(&'a self, name: &'a str) -> Inspect<'a> {
+        Inspect::new(&self.http_client, name)
+    }
+
+    /// Retrieve the history of an image
+    ///
+    /// The 'name' parameter may be a name, a name and tag, or an id.
+    #[must_use]
+    pub fn history<'a>(&'a self, name: &'a str) -> History<'a> {
+        History::new(&self.http_client, name)
+    }
+
+    /// Remove an image, along with any untagged parent images
+    ///
+    /// The 'name' parameter may be a name, a name and tag, or an id.
+    #[must_use]
+    pub fn remove<'a>(&'a self, name: &'a str) -> Remove<'a> {
+        Remove::new(&self.http_client, name)
+    }
+
+    /// Tag an image, so that it can be pushed to a repository
+    ///
+    /// The 'name' parameter may be a name, a name and tag, or an id. The
+    /// 'repo' parameter is the repository to tag the image into, such as
+    /// `registry.example.com/my-image`.
+    #[must_use]
+    pub fn tag<'a>(&'a self, name: &'a str, repo: &'a str) -> Tag<'a> {
+        Tag::new(&self.http_client, name, repo)
+    }
+
+    /// Push an image to a registry
+    #[must_use]
+    pub fn push<'a>(&'a self, name: &'a str) -> Push<'a> {
+        Push::new(&self.http_client, name)
+    }
+
+    /// Build an image from a tar archive of a build context.
+    ///
+    /// The context is a stream of the bytes of the archive, which may be
+    /// compressed. A [`BuildContext`] can be used to pack a directory on the
+    /// local host.
+    pub fn build<S>(&self, context: S) -> Build<'_>
+    where
+        S: Stream<Item = io::Result<Bytes>> + Send + 'static,
+    {
+        Build::new(&self.http_client, context)
+    }
+
+    /// Export an image as a tar archive.
+    ///
+    /// The 'name' parameter may be a name, a name and tag, or an id. Further
+    /// images may be added to the same archive with [`Save::image`].
+    #[must_use]
+    pub fn save<'a>(&'a self, name: &'a str) -> Save<'a> {
+        Save::new(&self.http_client, name)
+    }
 }
@@ -0,0 +1,157 @@
+use serde::Serialize;
+use std::fmt;
+
+/// The name of the header used to pass credentials to the Docker host
+pub(crate) const REGISTRY_AUTH_HEADER: &str = "X-Registry-Auth";
+
+/// Credentials for authenticating with a container registry
+///
+/// # Example
+/// ```no_run
+/// use longshoreman::{images::RegistryAuth, Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let auth = RegistryAuth::password("username", "password")
+///         .server_address("registry.example.com");
+///
+///     Docker::new()
+///         .images()
+///         .push("registry.example.com/my-image")
+///         .tag("latest")
+///         .auth(auth)
+///         .send()
+///         .await
+/// }
+/// ```
+#[derive(Clone, Default, PartialEq, Eq, Serialize)]
+pub struct RegistryAuth {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    username: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    password: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    email: Option<String>,
+    #[serde(rename = "serveraddress", skip_serializing_if = "Option::is_none")]
+    server_address: Option<String>,
+    #[serde(rename = "identitytoken", skip_serializing_if = "Option::is_none")]
+    identity_token: Option<String>,
+    #[serde(rename = "registrytoken", skip_serializing_if = "Option::is_none")]
+    registry_token: Option<String>,
+}
+
+impl RegistryAuth {
+    /// Authenticate with a username and password
+    #[must_use]
+    pub fn password(username: impl Into<String>, password: impl Into<String>) -> Self {
+        Self {
+            username: Some(username.into()),
+            password: Some(password.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Authenticate with an identity token, as returned by the registry when
+    /// logging in
+    #[must_use]
+    pub fn identity_token(token: impl Into<String>) -> Self {
+        Self {
+            identity_token: Some(token.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Authenticate with a bearer token which is passed directly to the
+    /// registry
+    #[must_use]
+    pub fn registry_token(token: impl Into<String>) -> Self {
+        Self {
+            registry_token: Some(token.into()),
+            ..Self::default()
+        }
+    }
+
+    /// Set the email address associated with the account
+    #[must_use]
+    pub fn email(mut self, email: impl Into<String>) -> Self {
+        self.email = Some(email.into());
+        self
+    }
+
+    /// Set the address of the registry, such as `registry.example.com`
+    #[must_use]
+    pub fn server_address(mut self, server_address: impl Into<String>) -> Self {
+        self.server_address = Some(server_address.into());
+        self
+    }
+
+    /// Encode the credentials as the value of the `X-Registry-Auth` header
+    pub(crate) fn header_value(&self) -> String {
+        let json = serde_json::to_vec(self).unwrap();
+        base64::encode_config(json, base64::URL_SAFE)
+    }
+}
+
+/// Credentials are redacted, so that they aren't accidentally logged
+impl fmt::Debug for RegistryAuth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fn redact(secret: Option<&String>) -> Option<&str> {
+            secret.map(|_| "<redacted>")
+        }
+
+        f.debug_struct("RegistryAuth")
+            .field("username", &self.username)
+            .field("password", &redact(self.password.as_ref()))
+            .field("email", &self.email)
+            .field("server_address", &self.server_address)
+            .field("identity_token", &redact(self.identity_token.as_ref()))
+            .field("registry_token", &redact(self.registry_token.as_ref()))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::RegistryAuth;
+
+    fn decode(auth: &RegistryAuth) -> serde_json::Value {
+        let json = base64::decode_config(auth.header_value(), base64::URL_SAFE).unwrap();
+        serde_json::from_slice(&json).unwrap()
+    }
+
+    #[test]
+    fn password() {
+        let auth = RegistryAuth::password("user", "pass").server_address("registry.example.com");
+
+        assert_eq!(
+            decode(&auth),
+            serde_json::json!({
+                "username": "user",
+                "password": "pass",
+                "serveraddress": "registry.example.com"
+            })
+        );
+    }
+
+    #[test]
+    fn identity_token() {
+        let auth = RegistryAuth::identity_token("token");
+
+        assert_eq!(
+            decode(&auth),
+            serde_json::json!({ "identitytoken": "token" })
+        );
+    }
+
+    #[test]
+    fn anonymous() {
+        assert_eq!(decode(&RegistryAuth::default()), serde_json::json!({}));
+    }
+
+    #[test]
+    fn debug_redacts_secrets() {
+        let auth = RegistryAuth::password("user", "hunter2");
+
+        assert!(!format!("{:?}", auth).contains("hunter2"));
+    }
+}
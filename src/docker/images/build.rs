@@ -0,0 +1,372 @@
+use crate::{
+    http_client::HttpClient,
+    images::{progress::StreamError, StatusMessage},
+    Error, Result,
+};
+use futures_util::stream::{Stream, TryStreamExt};
+use hyper::{body::Bytes, Body};
+use serde::{Deserialize, Serialize, Serializer};
+use std::{collections::BTreeMap, io};
+
+/// A request to build an image from a tar archive of a build context
+///
+/// The archive must contain a Dockerfile, and any files referenced by it. The
+/// archive is streamed to the Docker host, so it doesn't need to be held in
+/// memory.
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{images::BuildMessage, Docker, Result};
+/// use tokio::stream::StreamExt;
+/// # use hyper::body::Bytes;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let images = Docker::new().images();
+///
+///     # let context = tokio::stream::empty::<std::io::Result<Bytes>>();
+///     // 'context' is a stream of the bytes of a tar archive
+///     let mut stream = Box::pin(
+///         images
+///             .build(context)
+///             .tag("my-image:latest")
+///             .build_arg("VERSION", "1.0")
+///             .stream(),
+///     );
+///
+///     while let Some(message) = stream.next().await {
+///         match message? {
+///             BuildMessage::Stream(output) => print!("{}", output),
+///             BuildMessage::ImageId(id) => println!("built {}", id),
+///             BuildMessage::Status(status) => println!("{}", status.status),
+///         }
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Build<'a> {
+    http_client: &'a HttpClient,
+    context: Body,
+    query: Query<'a>,
+}
+
+impl<'a> Build<'a> {
+    pub(crate) fn new<S>(http_client: &'a HttpClient, context: S) -> Self
+    where
+        S: Stream<Item = io::Result<Bytes>> + Send + 'static,
+    {
+        let context = Body::wrap_stream(context);
+        let query = Query::default();
+        Self {
+            http_client,
+            context,
+            query,
+        }
+    }
+
+    /// A name and optional tag to apply to the image, in the `name:tag`
+    /// format.
+    ///
+    /// This may be called more than once to apply multiple tags.
+    #[must_use]
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.query.t.push(tag);
+        self
+    }
+
+    /// The path of the Dockerfile within the build context.
+    ///
+    /// default is 'Dockerfile'.
+    #[must_use]
+    pub fn dockerfile(mut self, dockerfile: &'a str) -> Self {
+        self.query.dockerfile = Some(dockerfile);
+        self
+    }
+
+    /// Set a build-time variable, which can be accessed with `ARG` in the
+    /// Dockerfile
+    #[must_use]
+    pub fn build_arg(mut self, key: &'a str, value: &'a str) -> Self {
+        self.query.buildargs.insert(key, value);
+        self
+    }
+
+    /// The build stage to build, in a multi-stage Dockerfile
+    #[must_use]
+    pub fn target(mut self, target: &'a str) -> Self {
+        self.query.target = Some(target);
+        self
+    }
+
+    /// Add a label to the image
+    #[must_use]
+    pub fn label(mut self, key: &'a str, value: &'a str) -> Self {
+        self.query.labels.insert(key, value);
+        self
+    }
+
+    /// Do not use the cache when building the image.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn no_cache(mut self, no_cache: bool) -> Self {
+        self.query.nocache = no_cache;
+        self
+    }
+
+    /// Attempt to pull newer versions of base images, even if they exist
+    /// locally.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn pull(mut self, pull: bool) -> Self {
+        self.query.pull = pull;
+        self
+    }
+
+    /// The platform to build for, in the format `os[/arch[/variant]]`
+    #[must_use]
+    pub fn platform(mut self, platform: &'a str) -> Self {
+        self.query.platform = Some(platform);
+        self
+    }
+
+    /// The networking mode for `RUN` instructions, such as `host` or `none`.
+    ///
+    /// default is 'default'.
+    #[must_use]
+    pub fn network_mode(mut self, network_mode: &'a str) -> Self {
+        self.query.networkmode = Some(network_mode);
+        self
+    }
+
+    /// An image to use as a source of cached layers.
+    ///
+    /// This may be called more than once to use multiple images.
+    #[must_use]
+    pub fn cache_from(mut self, image: &'a str) -> Self {
+        self.query.cachefrom.push(image);
+        self
+    }
+
+    /// Consume the request and return a stream of [`BuildMessage`]s.
+    ///
+    /// If the build fails, the stream returns an [`Error::Stream`].
+    pub fn stream(self) -> impl Stream<Item = Result<BuildMessage>> + 'a {
+        self.http_client
+            .post("/build")
+            .query(self.query)
+            .tar_body(self.context)
+            .into_stream_json()
+            .and_then(|message: Message| async move { message.into_result() })
+    }
+
+    /// Consume the request and return the ID of the built image
+    pub async fn send(self) -> Result<String> {
+        let image_id = self
+            .stream()
+            .try_fold(None, |image_id, message| async move {
+                Ok(match message {
+                    BuildMessage::ImageId(id) => Some(id),
+                    BuildMessage::Stream(output) => image_id.or_else(|| parse_image_id(&output)),
+                    BuildMessage::Status(_) => image_id,
+                })
+            })
+            .await?;
+
+        image_id.ok_or_else(|| Error::InvalidResponse("build did not return an image ID".into()))
+    }
+}
+
+/// Older versions of Docker only report the image ID in the build output
+fn parse_image_id(output: &str) -> Option<String> {
+    const PREFIX: &str = "Successfully built ";
+
+    let output = output.trim();
+    if output.starts_with(PREFIX) {
+        Some(output[PREFIX.len()..].to_owned())
+    } else {
+        None
+    }
+}
+
+#[derive(Debug, Default)]
+struct Query<'a> {
+    t: Vec<&'a str>,
+    dockerfile: Option<&'a str>,
+    buildargs: BTreeMap<&'a str, &'a str>,
+    target: Option<&'a str>,
+    labels: BTreeMap<&'a str, &'a str>,
+    nocache: bool,
+    pull: bool,
+    platform: Option<&'a str>,
+    networkmode: Option<&'a str>,
+    cachefrom: Vec<&'a str>,
+}
+
+/// The `t` parameter may be repeated, and maps and lists are JSON-encoded, so
+/// the query is serialised as a sequence of key-value pairs
+impl Serialize for Query<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> std::result::Result<S::Ok, S::Error> {
+        fn json<T: Serialize, E: serde::ser::Error>(value: &T) -> std::result::Result<String, E> {
+            serde_json::to_string(value).map_err(E::custom)
+        }
+
+        let mut pairs: Vec<(&str, String)> =
+            self.t.iter().map(|t| ("t", (*t).to_owned())).collect();
+
+        if let Some(dockerfile) = self.dockerfile {
+            pairs.push(("dockerfile", dockerfile.to_owned()));
+        }
+        if !self.buildargs.is_empty() {
+            pairs.push(("buildargs", json(&self.buildargs)?));
+        }
+        if let Some(target) = self.target {
+            pairs.push(("target", target.to_owned()));
+        }
+        if !self.labels.is_empty() {
+            pairs.push(("labels", json(&self.labels)?));
+        }
+        if self.nocache {
+            pairs.push(("nocache", true.to_string()));
+        }
+        if self.pull {
+            pairs.push(("pull", true.to_string()));
+        }
+        if let Some(platform) = self.platform {
+            pairs.push(("platform", platform.to_owned()));
+        }
+        if let Some(networkmode) = self.networkmode {
+            pairs.push(("networkmode", networkmode.to_owned()));
+        }
+        if !self.cachefrom.is_empty() {
+            pairs.push(("cachefrom", json(&self.cachefrom)?));
+        }
+
+        pairs.serialize(serializer)
+    }
+}
+
+/// A message streamed by the Docker host while building an image
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum BuildMessage {
+    /// Output from the build, such as the output of `RUN` instructions
+    Stream(String),
+
+    /// The ID of the built image
+    ImageId(String),
+
+    /// Progress while pulling a base image
+    Status(StatusMessage),
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Message {
+    Error(StreamError),
+    Stream { stream: String },
+    Aux { aux: Aux },
+    Status(StatusMessage),
+}
+
+#[derive(Debug, Deserialize)]
+struct Aux {
+    #[serde(rename = "ID")]
+    id: String,
+}
+
+impl Message {
+    fn into_result(self) -> Result<BuildMessage> {
+        match self {
+            Self::Error(stream_error) => Err(stream_error.into()),
+            Self::Stream { stream } => Ok(BuildMessage::Stream(stream)),
+            Self::Aux { aux } => Ok(BuildMessage::ImageId(aux.id)),
+            Self::Status(status_message) => Ok(BuildMessage::Status(status_message)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn decode(json: &str) -> Result<BuildMessage> {
+        serde_json::from_str::<Message>(json).unwrap().into_result()
+    }
+
+    #[test]
+    fn serialize_query() {
+        let mut query = Query {
+            t: vec!["image:latest", "image:1.0"],
+            nocache: true,
+            cachefrom: vec!["image:0.9"],
+            ..Query::default()
+        };
+        query.buildargs.insert("VERSION", "1.0");
+
+        assert_eq!(
+            serde_urlencoded::to_string(query).unwrap(),
+            "t=image%3Alatest&t=image%3A1.0&buildargs=%7B%22VERSION%22%3A%221.0%22%7D&nocache=true&\
+             cachefrom=%5B%22image%3A0.9%22%5D"
+        );
+    }
+
+    #[test]
+    fn stream_message() {
+        assert_eq!(
+            decode(r#"{"stream": "Step 1/2 : FROM alpine\n"}"#).unwrap(),
+            BuildMessage::Stream("Step 1/2 : FROM alpine\n".into())
+        );
+    }
+
+    #[test]
+    fn aux_message() {
+        assert_eq!(
+            decode(r#"{"aux": {"ID": "sha256:7a1b"}}"#).unwrap(),
+            BuildMessage::ImageId("sha256:7a1b".into())
+        );
+    }
+
+    #[test]
+    fn status_message() {
+        match decode(
+            r#"{"status": "Pulling fs layer", "progressDetail": {}, "id": "cbdbe7a5bc2a"}"#,
+        )
+        .unwrap()
+        {
+            BuildMessage::Status(status) => assert_eq!(status.status, "Pulling fs layer"),
+            other => panic!("expected a status message, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn error_message() {
+        let error = decode(
+            r#"{
+                "errorDetail": {
+                    "code": 1,
+                    "message": "The command '/bin/sh -c exit 1' returned a non-zero code: 1"
+                },
+                "error": "The command '/bin/sh -c exit 1' returned a non-zero code: 1"
+            }"#,
+        )
+        .unwrap_err();
+
+        match error {
+            Error::Stream { code, .. } => assert_eq!(code, Some(1)),
+            other => panic!("expected a stream error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn legacy_image_id() {
+        assert_eq!(
+            parse_image_id("Successfully built 7a1b2c3d\n"),
+            Some("7a1b2c3d".into())
+        );
+        assert_eq!(parse_image_id("Step 1/2 : FROM alpine\n"), None);
+    }
+}
@@ -0,0 +1,398 @@
+use crate::{blocking, images::dockerignore::DockerIgnore};
+use futures_util::stream::Stream;
+use hyper::body::Bytes;
+use std::{
+    fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+/// The build context of an image, read from a directory on the local host
+///
+/// The context is packed into a tar archive as it is streamed to the Docker
+/// host. Files matched by a `.dockerignore` file in the root of the directory
+/// are left out, following the same rules as the Docker CLI.
+///
+/// Entries in the archive are written in a consistent order, and their
+/// modification times, owners and permissions are normalised, so that packing
+/// the same files always produces the same archive.
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{images::BuildContext, Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let images = Docker::new().images();
+///
+///     let context = BuildContext::new("path/to/context");
+///
+///     let image_id = images
+///         .build(context.stream())
+///         .tag("my-image:latest")
+///         .send()
+///         .await?;
+///
+///     println!("built {}", image_id);
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct BuildContext {
+    root: PathBuf,
+    dockerfile: String,
+}
+
+impl BuildContext {
+    /// Create a build context from the contents of a directory
+    pub fn new(root: impl Into<PathBuf>) -> Self {
+        let root = root.into();
+        let dockerfile = String::from("Dockerfile");
+        Self { root, dockerfile }
+    }
+
+    /// The path of the Dockerfile, relative to the root of the context.
+    ///
+    /// The Dockerfile is always included in the archive, even if it is matched
+    /// by the `.dockerignore` file. If the Dockerfile isn't at the default
+    /// location, the same path must also be passed to
+    /// [`Build::dockerfile`](crate::images::Build::dockerfile).
+    ///
+    /// default is 'Dockerfile'.
+    #[must_use]
+    pub fn dockerfile(mut self, dockerfile: impl Into<String>) -> Self {
+        self.dockerfile = dockerfile.into();
+        self
+    }
+
+    /// Consume the context and return a stream of the bytes of the tar
+    /// archive.
+    ///
+    /// The directory is read on a blocking thread as the stream is polled, and
+    /// only a few chunks of the archive are buffered at a time.
+    pub fn stream(self) -> impl Stream<Item = io::Result<Bytes>> + Send + 'static {
+        blocking::write_stream(move |writer| self.write_to(writer).map(drop))
+    }
+
+    /// Write the tar archive of the context
+    fn write_to<W: Write>(&self, writer: W) -> io::Result<W> {
+        let dockerignore = match fs::read_to_string(self.root.join(".dockerignore")) {
+            Ok(contents) => DockerIgnore::parse(&contents),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => DockerIgnore::default(),
+            Err(e) => return Err(e),
+        };
+
+        let packer = Packer {
+            dockerignore,
+            dockerfile: self.dockerfile.trim_start_matches("./"),
+            builder: tar::Builder::new(writer),
+        };
+
+        packer.pack(&self.root)
+    }
+}
+
+/// Walks the context directory and appends the files which aren't ignored to
+/// the archive
+struct Packer<'a, W: Write> {
+    dockerignore: DockerIgnore,
+    dockerfile: &'a str,
+    builder: tar::Builder<W>,
+}
+
+impl<W: Write> Packer<'_, W> {
+    fn pack(mut self, root: &Path) -> io::Result<W> {
+        self.pack_dir(root, "")?;
+
+        let mut writer = self.builder.into_inner()?;
+        writer.flush()?;
+        Ok(writer)
+    }
+
+    fn pack_dir(&mut self, dir: &Path, prefix: &str) -> io::Result<()> {
+        let mut entries = fs::read_dir(dir)?.collect::<io::Result<Vec<_>>>()?;
+        entries.sort_by_key(fs::DirEntry::file_name);
+
+        for entry in entries {
+            let file_name = entry.file_name();
+            let file_name = file_name.to_str().ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    format!("path is not valid unicode: {}", entry.path().display()),
+                )
+            })?;
+
+            let path = format!("{}{}", prefix, file_name);
+            let excluded = self.is_excluded(&path);
+
+            let metadata = fs::symlink_metadata(entry.path())?;
+
+            if metadata.is_dir() {
+                // an exception may re-include files inside an excluded
+                // directory, and the Dockerfile is always included, so it can
+                // only be skipped if neither is inside it
+                let has_dockerfile = self.dockerfile.starts_with(&format!("{}/", path));
+                if excluded && !self.dockerignore.has_exceptions() && !has_dockerfile {
+                    continue;
+                }
+                if !excluded {
+                    self.append(&path, &entry.path(), &metadata)?;
+                }
+                self.pack_dir(&entry.path(), &format!("{}/", path))?;
+            } else if !excluded {
+                self.append(&path, &entry.path(), &metadata)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    fn is_excluded(&self, path: &str) -> bool {
+        // the Docker host needs these to perform the build
+        if path == self.dockerfile || path == ".dockerignore" {
+            return false;
+        }
+
+        self.dockerignore.is_excluded(path)
+    }
+
+    fn append(&mut self, path: &str, source: &Path, metadata: &fs::Metadata) -> io::Result<()> {
+        let mut header = tar::Header::new_gnu();
+        header.set_mtime(0);
+        header.set_uid(0);
+        header.set_gid(0);
+
+        let file_type = metadata.file_type();
+
+        if file_type.is_dir() {
+            header.set_entry_type(tar::EntryType::Directory);
+            header.set_mode(0o755);
+            header.set_size(0);
+            self.builder.append_data(&mut header, path, io::empty())
+        } else if file_type.is_symlink() {
+            header.set_entry_type(tar::EntryType::Symlink);
+            header.set_mode(0o777);
+            header.set_size(0);
+            // long targets are written as a GNU long link entry
+            self.builder
+                .append_link(&mut header, path, fs::read_link(source)?)
+        } else if file_type.is_file() {
+            header.set_entry_type(tar::EntryType::Regular);
+            header.set_mode(if is_executable(metadata) {
+                0o755
+            } else {
+                0o644
+            });
+            header.set_size(metadata.len());
+            self.builder
+                .append_data(&mut header, path, fs::File::open(source)?)
+        } else {
+            // sockets, pipes and devices can't be sent to the Docker host
+            Ok(())
+        }
+    }
+}
+
+#[cfg(unix)]
+fn is_executable(metadata: &fs::Metadata) -> bool {
+    use std::os::unix::fs::PermissionsExt;
+    metadata.permissions().mode() & 0o111 != 0
+}
+
+#[cfg(not(unix))]
+fn is_executable(_metadata: &fs::Metadata) -> bool {
+    false
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::stream::TryStreamExt;
+    use std::io::Read;
+
+    /// Create a directory of files for a test, removing any left over from a
+    /// previous run
+    fn context_dir(name: &str, files: &[(&str, &str)]) -> PathBuf {
+        let root = std::env::temp_dir().join(format!(
+            "longshoreman-context-{}-{}",
+            name,
+            std::process::id()
+        ));
+        let _ = fs::remove_dir_all(&root);
+
+        for (path, contents) in files {
+            let path = root.join(path);
+            fs::create_dir_all(path.parent().unwrap()).unwrap();
+            fs::write(path, contents).unwrap();
+        }
+
+        root
+    }
+
+    fn entries(archive: &[u8]) -> Vec<(String, tar::Header)> {
+        tar::Archive::new(archive)
+            .entries()
+            .unwrap()
+            .map(|entry| {
+                let entry = entry.unwrap();
+                let path = entry.path().unwrap().to_str().unwrap().to_owned();
+                (path, entry.header().clone())
+            })
+            .collect()
+    }
+
+    #[test]
+    fn dockerignore_is_applied() {
+        let root = context_dir(
+            "dockerignore",
+            &[
+                ("Dockerfile", "FROM alpine\n"),
+                (
+                    ".dockerignore",
+                    "target\n**/*.log\nDockerfile\n.dockerignore\n",
+                ),
+                ("src/main.rs", "fn main() {}\n"),
+                ("src/debug.log", ""),
+                ("target/debug/app", ""),
+            ],
+        );
+
+        let archive = BuildContext::new(&root).write_to(Vec::new()).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        let paths: Vec<String> = entries(&archive)
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect();
+        assert_eq!(
+            paths,
+            vec![".dockerignore", "Dockerfile", "src", "src/main.rs"]
+        );
+    }
+
+    #[test]
+    fn exceptions_inside_excluded_directories() {
+        let root = context_dir(
+            "exceptions",
+            &[
+                ("Dockerfile", "FROM alpine\n"),
+                (".dockerignore", "vendor\n!vendor/keep\n"),
+                ("vendor/keep/lib.rs", ""),
+                ("vendor/other/lib.rs", ""),
+            ],
+        );
+
+        let archive = BuildContext::new(&root).write_to(Vec::new()).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        let paths: Vec<String> = entries(&archive)
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect();
+        assert_eq!(
+            paths,
+            vec![
+                ".dockerignore",
+                "Dockerfile",
+                "vendor/keep",
+                "vendor/keep/lib.rs"
+            ]
+        );
+    }
+
+    #[test]
+    fn dockerfile_inside_excluded_directory() {
+        let root = context_dir(
+            "nested-dockerfile",
+            &[
+                ("docker/Dockerfile", "FROM alpine\n"),
+                ("docker/entrypoint.sh", ""),
+                (".dockerignore", "docker\n"),
+            ],
+        );
+
+        let archive = BuildContext::new(&root)
+            .dockerfile("docker/Dockerfile")
+            .write_to(Vec::new())
+            .unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        let paths: Vec<String> = entries(&archive)
+            .into_iter()
+            .map(|(path, _)| path)
+            .collect();
+        assert_eq!(paths, vec![".dockerignore", "docker/Dockerfile"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn long_symlink_target() {
+        let root = context_dir("symlink", &[("Dockerfile", "FROM alpine\n")]);
+        let target = format!("{}/file", "directory".repeat(20));
+        std::os::unix::fs::symlink(&target, root.join("link")).unwrap();
+
+        let archive = BuildContext::new(&root).write_to(Vec::new()).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        let mut archive = tar::Archive::new(archive.as_slice());
+        let link = archive
+            .entries()
+            .unwrap()
+            .map(Result::unwrap)
+            .find(|entry| entry.path().unwrap().to_str() == Some("link"))
+            .unwrap();
+
+        assert_eq!(link.header().entry_type(), tar::EntryType::Symlink);
+        assert_eq!(
+            link.link_name().unwrap().unwrap().to_str(),
+            Some(target.as_str())
+        );
+    }
+
+    #[test]
+    fn headers_are_normalised() {
+        let root = context_dir("headers", &[("Dockerfile", "FROM alpine\n")]);
+
+        let archive = BuildContext::new(&root).write_to(Vec::new()).unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        let entries = entries(&archive);
+        let (_, header) = &entries[0];
+        assert_eq!(header.mtime().unwrap(), 0);
+        assert_eq!(header.uid().unwrap(), 0);
+        assert_eq!(header.gid().unwrap(), 0);
+        assert_eq!(header.mode().unwrap(), 0o644);
+    }
+
+    #[tokio::test]
+    async fn stream_archive() {
+        let contents = "x".repeat(256 * 1024);
+        let root = context_dir(
+            "stream",
+            &[("Dockerfile", "FROM alpine\n"), ("data", &contents)],
+        );
+
+        let chunks: Vec<Bytes> = BuildContext::new(&root)
+            .stream()
+            .try_collect()
+            .await
+            .unwrap();
+        fs::remove_dir_all(&root).unwrap();
+
+        assert!(chunks.len() > 1);
+        let archive: Vec<u8> = chunks.concat();
+
+        let mut archive = tar::Archive::new(archive.as_slice());
+        let mut entry = archive
+            .entries()
+            .unwrap()
+            .map(Result::unwrap)
+            .find(|entry| entry.path().unwrap().to_str() == Some("data"))
+            .unwrap();
+
+        let mut data = String::new();
+        entry.read_to_string(&mut data).unwrap();
+        assert_eq!(data, contents);
+    }
+}
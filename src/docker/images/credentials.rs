@@ -0,0 +1,492 @@
+use crate::{images::RegistryAuth, Error, Result};
+use serde::Deserialize;
+use std::{
+    collections::HashMap,
+    env, fs,
+    io::{self, Write},
+    path::{Path, PathBuf},
+    process::{Command, Output, Stdio},
+};
+
+/// The registry used for images which don't name a registry
+const DEFAULT_REGISTRY: &str = "docker.io";
+
+/// The key under which Docker stores credentials for the default registry
+const DEFAULT_REGISTRY_KEY: &str = "https://index.docker.io/v1/";
+
+/// The username returned by credential helpers when the secret is an identity
+/// token
+const TOKEN_USERNAME: &str = "<token>";
+
+/// Credentials read from a Docker `config.json` file
+///
+/// Credentials are looked up in the same way as the Docker CLI. A credential
+/// helper configured for the registry in `credHelpers` takes precedence,
+/// followed by the default helper in `credsStore`, followed by credentials
+/// stored inline in `auths`.
+///
+/// [`Pull`](crate::images::Pull) and [`Push`](crate::images::Push) use this
+/// when `auth_from_config` is set. It can also be used to resolve credentials
+/// explicitly.
+///
+/// # Example
+/// ```no_run
+/// use longshoreman::{images::DockerConfig, Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let image = "registry.example.com/my-image";
+///
+///     let images = Docker::new().images();
+///     let config = DockerConfig::load().await?;
+///
+///     let mut pull = images.pull(image);
+///
+///     if let Some(auth) = config.resolve_image(image).await? {
+///         pull = pull.auth(auth);
+///     }
+///
+///     pull.send().await
+/// }
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DockerConfig {
+    #[serde(default)]
+    auths: HashMap<String, AuthEntry>,
+    #[serde(default)]
+    creds_store: Option<String>,
+    #[serde(default)]
+    cred_helpers: HashMap<String, String>,
+}
+
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+struct AuthEntry {
+    #[serde(default)]
+    auth: Option<String>,
+    #[serde(default)]
+    email: Option<String>,
+    #[serde(default, rename = "identitytoken")]
+    identity_token: Option<String>,
+}
+
+impl DockerConfig {
+    /// Load the Docker config file of the current user.
+    ///
+    /// The file is read from `$DOCKER_CONFIG/config.json` if `DOCKER_CONFIG`
+    /// is set, or `~/.docker/config.json` otherwise. If the file doesn't
+    /// exist, an empty config is returned.
+    pub async fn load() -> Result<Self> {
+        match default_path() {
+            Some(path) => Self::from_path(path).await,
+            None => Ok(Self::default()),
+        }
+    }
+
+    /// Load a Docker config file from the given path.
+    ///
+    /// If the file doesn't exist, an empty config is returned.
+    pub async fn from_path(path: impl AsRef<Path>) -> Result<Self> {
+        let path = path.as_ref().to_owned();
+        let display = path.display().to_string();
+
+        let contents = tokio::task::spawn_blocking(move || fs::read(path))
+            .await
+            .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+
+        match contents {
+            Ok(bytes) => serde_json::from_slice(&bytes)
+                .map_err(|e| Error::Credentials(format!("invalid config '{}': {}", display, e))),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(e) => Err(Error::Credentials(format!(
+                "failed to read '{}': {}",
+                display, e
+            ))),
+        }
+    }
+
+    /// Resolve the credentials for the registry of an image, such as
+    /// `registry.example.com/my-image:latest`
+    pub async fn resolve_image(&self, image: &str) -> Result<Option<RegistryAuth>> {
+        self.resolve(registry_hostname(image)).await
+    }
+
+    /// Resolve the credentials for a registry, such as `registry.example.com`.
+    ///
+    /// Returns `None` if no credentials are stored for the registry.
+    pub async fn resolve(&self, registry: &str) -> Result<Option<RegistryAuth>> {
+        let registry = normalise_hostname(registry);
+
+        let helper = self
+            .cred_helpers
+            .iter()
+            .find(|(key, _)| normalise_hostname(key) == registry)
+            .map(|(_, helper)| helper)
+            .or(self.creds_store.as_ref());
+
+        if let Some(helper) = helper {
+            let server_address = server_address(registry);
+            let credentials = run_helper(helper.clone(), server_address.clone()).await?;
+
+            if let Some(auth) = credentials {
+                return Ok(Some(auth.server_address(server_address)));
+            }
+        }
+
+        self.auths
+            .iter()
+            .find(|(key, _)| normalise_hostname(key) == registry)
+            .map(|(key, entry)| entry.to_registry_auth(key))
+            .transpose()
+    }
+}
+
+impl AuthEntry {
+    fn to_registry_auth(&self, server_address: &str) -> Result<RegistryAuth> {
+        let mut auth = if let Some(token) = &self.identity_token {
+            RegistryAuth::identity_token(token.as_str())
+        } else if let Some(encoded) = &self.auth {
+            let (username, password) = decode_auth(encoded)?;
+            RegistryAuth::password(username, password)
+        } else {
+            RegistryAuth::default()
+        };
+
+        if let Some(email) = &self.email {
+            auth = auth.email(email.as_str());
+        }
+
+        Ok(auth.server_address(server_address))
+    }
+}
+
+/// Decode an inline `auth` value, which is a base64-encoded `username:password`
+fn decode_auth(encoded: &str) -> Result<(String, String)> {
+    let invalid = || Error::Credentials("invalid 'auth' value in docker config".into());
+
+    let decoded = base64::decode(encoded).map_err(|_| invalid())?;
+    let decoded = String::from_utf8(decoded).map_err(|_| invalid())?;
+
+    let mut parts = decoded.splitn(2, ':');
+    match (parts.next(), parts.next()) {
+        (Some(username), Some(password)) => Ok((username.to_owned(), password.to_owned())),
+        _ => Err(invalid()),
+    }
+}
+
+/// The path of the Docker config file of the current user
+fn default_path() -> Option<PathBuf> {
+    if let Some(dir) = env::var_os("DOCKER_CONFIG") {
+        return Some(PathBuf::from(dir).join("config.json"));
+    }
+
+    env::var_os("HOME")
+        .or_else(|| env::var_os("USERPROFILE"))
+        .map(|home| PathBuf::from(home).join(".docker").join("config.json"))
+}
+
+/// Return the hostname of the registry of an image.
+///
+/// Images which don't name a registry, such as `ubuntu` or `library/ubuntu`,
+/// are hosted on Docker Hub.
+#[must_use]
+pub fn registry_hostname(image: &str) -> &str {
+    match image.find('/') {
+        Some(i) => {
+            let first = &image[..i];
+            if first.contains('.') || first.contains(':') || first == "localhost" {
+                first
+            } else {
+                DEFAULT_REGISTRY
+            }
+        }
+        None => DEFAULT_REGISTRY,
+    }
+}
+
+/// Strip the scheme and path from a registry address, and resolve the aliases
+/// of Docker Hub
+fn normalise_hostname(address: &str) -> &str {
+    let address = address
+        .trim_start_matches("https://")
+        .trim_start_matches("http://");
+    let hostname = address.split('/').next().unwrap_or(address);
+
+    match hostname {
+        "index.docker.io" | "registry-1.docker.io" => DEFAULT_REGISTRY,
+        hostname => hostname,
+    }
+}
+
+/// The address under which credentials for a registry are stored
+fn server_address(registry: &str) -> String {
+    if registry == DEFAULT_REGISTRY {
+        DEFAULT_REGISTRY_KEY.to_owned()
+    } else {
+        registry.to_owned()
+    }
+}
+
+#[derive(Debug, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+struct HelperOutput {
+    username: String,
+    secret: String,
+}
+
+impl From<HelperOutput> for RegistryAuth {
+    fn from(output: HelperOutput) -> Self {
+        if output.username == TOKEN_USERNAME {
+            Self::identity_token(output.secret)
+        } else {
+            Self::password(output.username, output.secret)
+        }
+    }
+}
+
+/// Run `docker-credential-<helper> get` to retrieve the credentials for a
+/// registry.
+///
+/// Returns `None` if the helper isn't installed.
+async fn run_helper(helper: String, server_address: String) -> Result<Option<RegistryAuth>> {
+    tokio::task::spawn_blocking(move || {
+        let program = format!("docker-credential-{}", helper);
+
+        let spawned = Command::new(&program)
+            .arg("get")
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped())
+            .spawn();
+
+        let mut child = match spawned {
+            Ok(child) => child,
+            // a config copied from another machine may name a helper which
+            // isn't installed here, which is treated as having no credentials
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(None),
+            Err(e) => {
+                return Err(Error::Credentials(format!(
+                    "failed to run '{}': {}",
+                    program, e
+                )))
+            }
+        };
+
+        child
+            .stdin
+            .take()
+            .expect("stdin is piped")
+            .write_all(server_address.as_bytes())?;
+
+        let output = child.wait_with_output()?;
+
+        parse_helper_output(&program, &output)
+    })
+    .await
+    .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?
+}
+
+/// Interpret the output of a credential helper.
+///
+/// Helpers report missing credentials with a non-zero exit code, and a message
+/// on stdout or stderr.
+fn parse_helper_output(program: &str, output: &Output) -> Result<Option<RegistryAuth>> {
+    if output.status.success() {
+        let credentials: HelperOutput = serde_json::from_slice(&output.stdout)
+            .map_err(|e| Error::Credentials(format!("invalid output from '{}': {}", program, e)))?;
+        return Ok(Some(credentials.into()));
+    }
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    let stderr = String::from_utf8_lossy(&output.stderr);
+
+    if stdout.contains("credentials not found") || stderr.contains("credentials not found") {
+        return Ok(None);
+    }
+
+    let message: Vec<&str> = vec![stdout.trim(), stderr.trim()]
+        .into_iter()
+        .filter(|message| !message.is_empty())
+        .collect();
+
+    Err(Error::Credentials(format!(
+        "'{}' failed ({}): {}",
+        program,
+        output.status,
+        message.join("\n")
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn registry_hostnames() {
+        assert_eq!(registry_hostname("ubuntu"), "docker.io");
+        assert_eq!(registry_hostname("library/ubuntu:18.04"), "docker.io");
+        assert_eq!(
+            registry_hostname("registry.example.com/foo/bar:latest"),
+            "registry.example.com"
+        );
+        assert_eq!(registry_hostname("localhost:5000/foo"), "localhost:5000");
+        assert_eq!(registry_hostname("localhost/foo"), "localhost");
+    }
+
+    #[test]
+    fn normalise_hostnames() {
+        assert_eq!(
+            normalise_hostname("https://index.docker.io/v1/"),
+            "docker.io"
+        );
+        assert_eq!(
+            normalise_hostname("https://registry.example.com"),
+            "registry.example.com"
+        );
+        assert_eq!(
+            normalise_hostname("registry.example.com/v2/"),
+            "registry.example.com"
+        );
+    }
+
+    #[tokio::test]
+    async fn resolve_inline_auths() {
+        let config: DockerConfig = serde_json::from_str(
+            r#"{
+                "auths": {
+                    "https://index.docker.io/v1/": {
+                        "auth": "dXNlcm5hbWU6cGFzczp3b3Jk"
+                    },
+                    "registry.example.com": {
+                        "identitytoken": "TOKEN"
+                    }
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.resolve_image("ubuntu").await.unwrap(),
+            Some(
+                RegistryAuth::password("username", "pass:word")
+                    .server_address("https://index.docker.io/v1/")
+            )
+        );
+        assert_eq!(
+            config
+                .resolve_image("registry.example.com/foo")
+                .await
+                .unwrap(),
+            Some(RegistryAuth::identity_token("TOKEN").server_address("registry.example.com"))
+        );
+        assert_eq!(config.resolve("other.example.com").await.unwrap(), None);
+    }
+
+    #[test]
+    fn deserialise_config() {
+        let config: DockerConfig = serde_json::from_str(
+            r#"{
+                "auths": {
+                    "registry.example.com": {}
+                },
+                "credsStore": "desktop",
+                "credHelpers": {
+                    "gcr.io": "gcloud"
+                },
+                "HttpHeaders": {
+                    "User-Agent": "Docker-Client/19.03.8 (linux)"
+                }
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(config.creds_store, Some("desktop".to_owned()));
+        assert_eq!(config.cred_helpers["gcr.io"], "gcloud");
+    }
+
+    #[tokio::test]
+    async fn missing_config_file() {
+        let config = DockerConfig::from_path("/path/that/does/not/exist/config.json")
+            .await
+            .unwrap();
+        assert_eq!(config, DockerConfig::default());
+    }
+
+    #[tokio::test]
+    async fn missing_helper() {
+        let config: DockerConfig = serde_json::from_str(
+            r#"{
+                "auths": {
+                    "registry.example.com": {
+                        "identitytoken": "TOKEN"
+                    }
+                },
+                "credsStore": "longshoreman-missing-helper"
+            }"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            config.resolve("registry.example.com").await.unwrap(),
+            Some(RegistryAuth::identity_token("TOKEN").server_address("registry.example.com"))
+        );
+        assert_eq!(config.resolve("docker.io").await.unwrap(), None);
+    }
+
+    #[tokio::test]
+    async fn unreadable_config_file() {
+        // a directory can't be read as a file
+        match DockerConfig::from_path(std::env::temp_dir()).await {
+            Err(Error::Credentials(message)) => assert!(message.contains("failed to read")),
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[test]
+    fn helper_output() {
+        let output: HelperOutput = serde_json::from_str(
+            r#"{"ServerURL": "registry.example.com", "Username": "<token>", "Secret": "TOKEN"}"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            RegistryAuth::from(output),
+            RegistryAuth::identity_token("TOKEN")
+        );
+    }
+
+    #[test]
+    fn invalid_inline_auth() {
+        match decode_auth("not base64!") {
+            Err(Error::Credentials(_)) => {}
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn failed_helper() {
+        use std::os::unix::process::ExitStatusExt;
+
+        let output = |stdout: &str, stderr: &str| Output {
+            status: std::process::ExitStatus::from_raw(1 << 8),
+            stdout: stdout.as_bytes().to_vec(),
+            stderr: stderr.as_bytes().to_vec(),
+        };
+
+        let not_found = output("credentials not found in native keychain\n", "");
+        assert_eq!(
+            parse_helper_output("docker-credential-desktop", &not_found).unwrap(),
+            None
+        );
+
+        let failed = output("", "error getting credentials: keychain is locked\n");
+        match parse_helper_output("docker-credential-desktop", &failed) {
+            Err(Error::Credentials(message)) => {
+                assert!(message.contains("docker-credential-desktop"));
+                assert!(message.contains("keychain is locked"));
+            }
+            result => panic!("unexpected result: {:?}", result),
+        }
+    }
+}
@@ -0,0 +1,300 @@
+//! Matching of paths against the patterns in a `.dockerignore` file
+//!
+//! The semantics follow the Docker CLI. Patterns use the syntax of Go's
+//! `filepath.Match`, extended with `**` to match any number of directories.
+//! Patterns prefixed with `!` are exceptions, which re-include paths excluded
+//! by earlier patterns. The last pattern to match a path decides whether it is
+//! excluded.
+
+/// A parsed `.dockerignore` file
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub(crate) struct DockerIgnore {
+    patterns: Vec<Pattern>,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+struct Pattern {
+    chars: Vec<char>,
+    components: usize,
+    exception: bool,
+}
+
+impl DockerIgnore {
+    /// Parse the contents of a `.dockerignore` file
+    pub fn parse(contents: &str) -> Self {
+        let patterns = contents
+            .lines()
+            .map(str::trim)
+            .filter(|line| !line.is_empty() && !line.starts_with('#'))
+            .filter_map(Pattern::parse)
+            .collect();
+
+        Self { patterns }
+    }
+
+    /// Returns true if the file contains exceptions (patterns prefixed with
+    /// `!`)
+    pub fn has_exceptions(&self) -> bool {
+        self.patterns.iter().any(|pattern| pattern.exception)
+    }
+
+    /// Returns true if the given path should be excluded from the build
+    /// context.
+    ///
+    /// The path must be relative to the root of the context, and use `/` as a
+    /// separator.
+    pub fn is_excluded(&self, path: &str) -> bool {
+        let parent_dirs: Vec<&str> = match path.rfind('/') {
+            Some(i) => path[..i].split('/').collect(),
+            None => Vec::new(),
+        };
+
+        let mut excluded = false;
+
+        for pattern in &self.patterns {
+            let mut matched = pattern.matches(path);
+
+            // a pattern also matches the contents of any directory it matches
+            if !matched && !parent_dirs.is_empty() && pattern.components <= parent_dirs.len() {
+                matched = pattern.matches(&parent_dirs[..pattern.components].join("/"));
+            }
+
+            if matched {
+                excluded = !pattern.exception;
+            }
+        }
+
+        excluded
+    }
+}
+
+impl Pattern {
+    fn parse(line: &str) -> Option<Self> {
+        let (exception, pattern) = if line.starts_with('!') {
+            (true, line[1..].trim())
+        } else {
+            (false, line)
+        };
+
+        let pattern = clean(pattern);
+        if pattern.is_empty() {
+            return None;
+        }
+
+        Some(Self {
+            components: pattern.split('/').count(),
+            chars: pattern.chars().collect(),
+            exception,
+        })
+    }
+
+    fn matches(&self, path: &str) -> bool {
+        let path: Vec<char> = path.chars().collect();
+        glob_match(&self.chars, &path)
+    }
+}
+
+/// Normalise a pattern in the same way as Go's `filepath.Clean`, and strip any
+/// leading `/`, since patterns are relative to the root of the context
+fn clean(pattern: &str) -> String {
+    let mut components: Vec<&str> = Vec::new();
+
+    for component in pattern.split('/') {
+        match component {
+            "" | "." => {}
+            ".." => {
+                components.pop();
+            }
+            component => components.push(component),
+        }
+    }
+
+    components.join("/")
+}
+
+/// Match a path against a glob pattern.
+///
+/// `*` matches any sequence of characters except `/`, `**` matches any
+/// sequence of characters (including `/`), and `**/` matches zero or more
+/// directories.
+fn glob_match(pattern: &[char], path: &[char]) -> bool {
+    let first = match pattern.first() {
+        Some(&c) => c,
+        None => return path.is_empty(),
+    };
+
+    match first {
+        '*' if pattern.get(1) == Some(&'*') => {
+            if pattern.get(2) == Some(&'/') {
+                let rest = &pattern[3..];
+                glob_match(rest, path)
+                    || (0..path.len()).any(|i| path[i] == '/' && glob_match(rest, &path[i + 1..]))
+            } else {
+                let rest = &pattern[2..];
+                (0..=path.len()).any(|i| glob_match(rest, &path[i..]))
+            }
+        }
+        '*' => {
+            let rest = &pattern[1..];
+            for i in 0..=path.len() {
+                if glob_match(rest, &path[i..]) {
+                    return true;
+                }
+                if path.get(i) == Some(&'/') {
+                    break;
+                }
+            }
+            false
+        }
+        '?' => match path.first() {
+            Some(&c) if c != '/' => glob_match(&pattern[1..], &path[1..]),
+            _ => false,
+        },
+        '[' => match parse_class(&pattern[1..]) {
+            Some((class, rest)) => match path.first() {
+                Some(&c) if c != '/' && class.matches(c) => glob_match(rest, &path[1..]),
+                _ => false,
+            },
+            // an unterminated class is treated as a literal '['
+            None => path.first() == Some(&'[') && glob_match(&pattern[1..], &path[1..]),
+        },
+        '\\' if pattern.len() > 1 => {
+            path.first() == Some(&pattern[1]) && glob_match(&pattern[2..], &path[1..])
+        }
+        literal => path.first() == Some(&literal) && glob_match(&pattern[1..], &path[1..]),
+    }
+}
+
+/// A character class, such as `[a-z]` or `[^0-9]`
+struct Class {
+    negated: bool,
+    ranges: Vec<(char, char)>,
+}
+
+impl Class {
+    fn matches(&self, c: char) -> bool {
+        let in_class = self.ranges.iter().any(|&(low, high)| low <= c && c <= high);
+        in_class != self.negated
+    }
+}
+
+/// Parse a character class, following the opening `[`.
+///
+/// Returns the class and the remainder of the pattern, or `None` if the class
+/// is not terminated.
+fn parse_class(pattern: &[char]) -> Option<(Class, &[char])> {
+    let negated = match pattern.first() {
+        Some('^') | Some('!') => true,
+        _ => false,
+    };
+
+    let mut i = usize::from(negated);
+    let mut ranges = Vec::new();
+
+    loop {
+        let low = match pattern.get(i) {
+            Some(']') if !ranges.is_empty() => {
+                return Some((Class { negated, ranges }, &pattern[i + 1..]));
+            }
+            Some('\\') => {
+                i += 1;
+                *pattern.get(i)?
+            }
+            Some(&c) => c,
+            None => return None,
+        };
+        i += 1;
+
+        let mut high = low;
+        if pattern.get(i) == Some(&'-') {
+            match pattern.get(i + 1) {
+                Some(']') | None => {}
+                Some('\\') => {
+                    high = *pattern.get(i + 2)?;
+                    i += 3;
+                }
+                Some(&c) => {
+                    high = c;
+                    i += 2;
+                }
+            }
+        }
+
+        ranges.push((low, high));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::DockerIgnore;
+
+    #[test]
+    fn simple_patterns() {
+        let ignore = DockerIgnore::parse(
+            r"
+            # comment
+            target
+            *.log
+            /secrets/*.key
+            ",
+        );
+
+        assert!(ignore.is_excluded("target"));
+        assert!(ignore.is_excluded("target/debug/build"));
+        assert!(ignore.is_excluded("debug.log"));
+        assert!(!ignore.is_excluded("logs/debug.log"));
+        assert!(ignore.is_excluded("secrets/id.key"));
+        assert!(!ignore.is_excluded("secrets/id.pub"));
+        assert!(!ignore.is_excluded("src/main.rs"));
+    }
+
+    #[test]
+    fn double_star() {
+        let ignore = DockerIgnore::parse("**/*.tmp\ndocs/**\n");
+
+        assert!(ignore.is_excluded("a.tmp"));
+        assert!(ignore.is_excluded("a/b/c.tmp"));
+        assert!(ignore.is_excluded("docs/index.md"));
+        assert!(ignore.is_excluded("docs/api/index.md"));
+        assert!(!ignore.is_excluded("src/a.rs"));
+    }
+
+    #[test]
+    fn exceptions() {
+        let ignore = DockerIgnore::parse("*.md\n!README*.md\nREADME-secret.md\n");
+
+        assert!(ignore.has_exceptions());
+        assert!(ignore.is_excluded("CHANGELOG.md"));
+        assert!(!ignore.is_excluded("README.md"));
+        assert!(ignore.is_excluded("README-secret.md"));
+    }
+
+    #[test]
+    fn exception_inside_excluded_directory() {
+        let ignore = DockerIgnore::parse("vendor\n!vendor/keep\n");
+
+        assert!(ignore.is_excluded("vendor/other/file"));
+        assert!(!ignore.is_excluded("vendor/keep"));
+        assert!(!ignore.is_excluded("vendor/keep/file"));
+    }
+
+    #[test]
+    fn wildcards_and_classes() {
+        let ignore = DockerIgnore::parse("file?.txt\nlog[0-9]\n[!a-c]*.bak\n");
+
+        assert!(ignore.is_excluded("file1.txt"));
+        assert!(!ignore.is_excluded("file10.txt"));
+        assert!(ignore.is_excluded("log7"));
+        assert!(!ignore.is_excluded("logx"));
+        assert!(ignore.is_excluded("z.bak"));
+        assert!(!ignore.is_excluded("a.bak"));
+    }
+
+    #[test]
+    fn patterns_are_cleaned() {
+        let ignore = DockerIgnore::parse("./build/\n/dist//out\n");
+
+        assert!(ignore.is_excluded("build/output"));
+        assert!(ignore.is_excluded("dist/out"));
+    }
+}
@@ -0,0 +1,100 @@
+use crate::{http_client::HttpClient, utils::null_as_default, Result};
+use chrono::{serde::ts_seconds, DateTime, Utc};
+use serde::Deserialize;
+
+/// A request to retrieve the history of an image
+///
+/// # Example
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let history = Docker::new().images().history("alpine").send().await?;
+///
+///     for layer in history {
+///         println!("{} {}", layer.id, layer.created_by);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct History<'a> {
+    http_client: &'a HttpClient,
+    name: &'a str,
+}
+
+impl<'a> History<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, name: &'a str) -> Self {
+        Self { http_client, name }
+    }
+
+    /// Consume the request and return the layers of the image, newest first
+    pub async fn send(self) -> Result<Vec<Response>> {
+        let endpoint = format!("/images/{}/history", self.name);
+        self.http_client.get(endpoint).into_json().await
+    }
+}
+
+/// A layer in the history of an image
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Response {
+    /// The ID of the layer.
+    ///
+    /// This is `<missing>` for layers which were not built locally.
+    pub id: String,
+
+    /// The time the layer was created
+    #[serde(with = "ts_seconds")]
+    pub created: DateTime<Utc>,
+
+    /// The command which created the layer
+    pub created_by: String,
+
+    /// The tags which refer to this layer
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub tags: Vec<String>,
+
+    /// The size of the layer, in bytes
+    pub size: i64,
+
+    /// The comment attached to the layer
+    #[serde(default)]
+    pub comment: String,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Response;
+
+    #[test]
+    fn deserialise_response() {
+        let response: Vec<Response> = serde_json::from_str(
+            r#"[
+                {
+                    "Id": "sha256:3e9b2a3e8c1b1b4a5e2b0f4b5e6b0f1c9d2a3e8c1b1b4a5e2b0f4b5e6b0f1c9d",
+                    "Created": 1588180800,
+                    "CreatedBy": "/bin/sh -c #(nop)  CMD [\"/bin/sh\"]",
+                    "Tags": ["alpine:latest"],
+                    "Size": 0,
+                    "Comment": ""
+                },
+                {
+                    "Id": "<missing>",
+                    "Created": 1588180799,
+                    "CreatedBy": "/bin/sh -c #(nop) ADD file:b91adb67b670d3a6ff9463e48b7def903ed516be66fc4e44e4c34326f64b2f3f in / ",
+                    "Tags": null,
+                    "Size": 5613158,
+                    "Comment": ""
+                }
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(response.len(), 2);
+        assert_eq!(response[1].id, "<missing>");
+        assert!(response[1].tags.is_empty());
+    }
+}
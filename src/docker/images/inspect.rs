@@ -0,0 +1,237 @@
+use crate::{
+    containers::{Config, GraphDriver},
+    http_client::HttpClient,
+    utils::{empty_string_as_none, null_as_default},
+    Result,
+};
+use chrono::{DateTime, Utc};
+use serde::Deserialize;
+
+/// A request to retrieve low-level information about an image
+///
+/// # Example
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let image = Docker::new().images().inspect("alpine").send().await?;
+///
+///     println!("{}/{}: {} layers", image.os, image.architecture, image.root_fs.layers.len());
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Inspect<'a> {
+    http_client: &'a HttpClient,
+    name: &'a str,
+}
+
+impl<'a> Inspect<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, name: &'a str) -> Self {
+        Self { http_client, name }
+    }
+
+    /// Consume the request and return information about the image
+    pub async fn send(self) -> Result<Response> {
+        let endpoint = format!("/images/{}/json", self.name);
+        self.http_client.get(endpoint).into_json().await
+    }
+}
+
+/// Low-level information about a Docker image
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Response {
+    /// The unique ID of the image
+    pub id: String,
+
+    /// The tags which refer to this image
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub repo_tags: Vec<String>,
+
+    /// The content-addressable digests of the image
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub repo_digests: Vec<String>,
+
+    /// The ID of the parent image, if any
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub parent: Option<String>,
+
+    /// The comment given when the image was committed
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub comment: Option<String>,
+
+    /// The time the image was created
+    pub created: DateTime<Utc>,
+
+    /// The ID of the container from which the image was committed, if any
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub container: Option<String>,
+
+    /// The configuration of the container from which the image was committed
+    #[serde(default)]
+    pub container_config: Option<Config>,
+
+    /// The version of Docker used to build the image
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub docker_version: Option<String>,
+
+    /// The author of the image
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub author: Option<String>,
+
+    /// The default configuration of containers created from this image
+    #[serde(default)]
+    pub config: Option<Config>,
+
+    /// The CPU architecture the image runs on
+    pub architecture: String,
+
+    /// The CPU architecture variant, such as `v7` for `arm`
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub variant: Option<String>,
+
+    /// The operating system the image runs on
+    pub os: String,
+
+    /// The version of the operating system (only used for Windows images)
+    #[serde(default, deserialize_with = "empty_string_as_none")]
+    pub os_version: Option<String>,
+
+    /// The size of the image, in bytes
+    pub size: i64,
+
+    /// The virtual size of the image, in bytes
+    #[serde(default)]
+    pub virtual_size: i64,
+
+    /// The storage driver used for the image filesystem
+    pub graph_driver: GraphDriver,
+
+    /// The layers of the image filesystem
+    #[serde(rename = "RootFS")]
+    pub root_fs: RootFs,
+
+    /// Metadata which is local to the Docker host
+    #[serde(default)]
+    pub metadata: Metadata,
+}
+
+/// The layers of an image filesystem
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct RootFs {
+    /// The type of filesystem, usually `layers`
+    #[serde(rename = "Type")]
+    pub kind: String,
+
+    /// The digests of the layers, from the bottom of the stack to the top
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub layers: Vec<String>,
+}
+
+/// Metadata about an image which is local to the Docker host
+#[derive(Debug, Clone, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Metadata {
+    /// The last time the image was tagged, if it has been tagged locally
+    #[serde(default)]
+    pub last_tag_time: Option<DateTime<Utc>>,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Response;
+
+    #[test]
+    #[allow(clippy::too_many_lines)]
+    fn deserialise_response() {
+        let response: Response = serde_json::from_str(
+            r##"{
+                "Id": "sha256:f70734b6a266dcb5f44c383274821207885b549b75c8e119404917a61335981a",
+                "RepoTags": ["alpine:latest"],
+                "RepoDigests": [
+                    "alpine@sha256:9a839e63dad54c3a6d1834e29692c8492d93f90c59c978c1ed79109ea4fb9a54"
+                ],
+                "Parent": "",
+                "Comment": "",
+                "Created": "2020-04-24T01:05:03.92860976Z",
+                "Container": "5fd4e5b3da9e1b5c3d6f7b52e0b05f0d87a6b64f7e2e7a8e0e1a0b0c0d0e0f00",
+                "ContainerConfig": {
+                    "Hostname": "5fd4e5b3da9e",
+                    "Domainname": "",
+                    "User": "",
+                    "AttachStdin": false,
+                    "AttachStdout": false,
+                    "AttachStderr": false,
+                    "Tty": false,
+                    "OpenStdin": false,
+                    "StdinOnce": false,
+                    "Env": ["PATH=/usr/local/sbin:/usr/local/bin:/usr/sbin:/usr/bin:/sbin:/bin"],
+                    "Cmd": ["/bin/sh", "-c", "#(nop) ", "CMD [\"/bin/sh\"]"],
+                    "Image": "sha256:3fd9065eaf02feaf94d68376da52541925650b81698c53c6824d92ff63f98353",
+                    "Volumes": null,
+                    "WorkingDir": "",
+                    "Entrypoint": null,
+                    "OnBuild": null,
+                    "Labels": {}
+                },
+                "DockerVersion": "18.09.7",
+                "Author": "",
+                "Config": {
+                    "Hostname": "",
+                    "Domainname": "",
+                    "User": "",
+                    "AttachStdin": false,
+                    "AttachStdout": false,
+                    "AttachStderr": false,
+                    "Tty": false,
+                    "OpenStdin": false,
+                    "StdinOnce": false,
+                    "Env": ["PATH=/usr/local/sbin:/usr/local/bin:/usr/sbin:/usr/bin:/sbin:/bin"],
+                    "Cmd": ["/bin/sh"],
+                    "Image": "sha256:3fd9065eaf02feaf94d68376da52541925650b81698c53c6824d92ff63f98353",
+                    "Volumes": null,
+                    "WorkingDir": "",
+                    "Entrypoint": null,
+                    "OnBuild": null,
+                    "Labels": null
+                },
+                "Architecture": "amd64",
+                "Os": "linux",
+                "Size": 5613158,
+                "VirtualSize": 5613158,
+                "GraphDriver": {
+                    "Data": {
+                        "MergedDir": "/var/lib/docker/overlay2/4b0ea/merged",
+                        "UpperDir": "/var/lib/docker/overlay2/4b0ea/diff",
+                        "WorkDir": "/var/lib/docker/overlay2/4b0ea/work"
+                    },
+                    "Name": "overlay2"
+                },
+                "RootFS": {
+                    "Type": "layers",
+                    "Layers": [
+                        "sha256:3e207b409db364b595ba862cdc12be96dcdad8e36c59a03b7b3b61c946a5741a"
+                    ]
+                },
+                "Metadata": {
+                    "LastTagTime": "0001-01-01T00:00:00Z"
+                }
+            }"##,
+        )
+        .unwrap();
+
+        assert_eq!(response.repo_tags, vec!["alpine:latest"]);
+        assert_eq!(response.parent, None);
+        assert_eq!(response.architecture, "amd64");
+        assert_eq!(response.os, "linux");
+        assert_eq!(response.variant, None);
+        assert_eq!(response.config.unwrap().cmd, vec!["/bin/sh"]);
+        assert_eq!(response.root_fs.kind, "layers");
+        assert_eq!(response.root_fs.layers.len(), 1);
+        assert_eq!(response.graph_driver.name, "overlay2");
+    }
+}
@@ -0,0 +1,184 @@
+use crate::{
+    http_client::HttpClient,
+    utils::{empty_string_as_none, null_as_default},
+    Filters, Result,
+};
+use chrono::{serde::ts_seconds, DateTime, Utc};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+/// A request to list local images
+///
+/// # Example
+/// ```no_run
+/// use longshoreman::{Docker, Filters, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let images = Docker::new()
+///         .images()
+///         .list()
+///         .filters(Filters::new().dangling(true))
+///         .send()
+///         .await?;
+///
+///     for image in images {
+///         println!("{} {:?}", image.id, image.repo_tags);
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug, Clone)]
+pub struct List<'a> {
+    http_client: &'a HttpClient,
+    query: Query,
+}
+
+impl<'a> List<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient) -> Self {
+        let query = Query::default();
+        Self { http_client, query }
+    }
+
+    /// Show intermediate image layers as well as top-level images.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn all(mut self, all: bool) -> Self {
+        self.query.all = all;
+        self
+    }
+
+    /// Return the digests of each image.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn digests(mut self, digests: bool) -> Self {
+        self.query.digests = digests;
+        self
+    }
+
+    /// Only return images which match the given [`Filters`]
+    #[must_use]
+    pub fn filters(mut self, filters: Filters) -> Self {
+        self.query.filters = filters;
+        self
+    }
+
+    /// Consume the request and return a list of Docker images
+    pub async fn send(self) -> Result<Vec<Response>> {
+        self.http_client
+            .get("/images/json")
+            .query(self.query)
+            .into_json()
+            .await
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+struct Query {
+    all: bool,
+    digests: bool,
+    #[serde(skip_serializing_if = "Filters::is_empty")]
+    filters: Filters,
+}
+
+/// A summary of a local Docker image
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "PascalCase")]
+pub struct Response {
+    /// The unique ID of the image
+    pub id: String,
+
+    /// The ID of the parent image, if any
+    #[serde(
+        default,
+        rename = "ParentId",
+        deserialize_with = "empty_string_as_none"
+    )]
+    pub parent_id: Option<String>,
+
+    /// The tags which refer to this image
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub repo_tags: Vec<String>,
+
+    /// The content-addressable digests of the image
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub repo_digests: Vec<String>,
+
+    /// The time the image was created
+    #[serde(with = "ts_seconds")]
+    pub created: DateTime<Utc>,
+
+    /// The size of the image, including all of its layers, in bytes
+    pub size: i64,
+
+    /// The size of the layers shared with other images, in bytes.
+    ///
+    /// This is `-1` if it has not been calculated.
+    pub shared_size: i64,
+
+    /// The virtual size of the image, in bytes
+    #[serde(default)]
+    pub virtual_size: i64,
+
+    /// User-defined metadata
+    #[serde(default, deserialize_with = "null_as_default")]
+    pub labels: HashMap<String, String>,
+
+    /// The number of containers using the image.
+    ///
+    /// This is `-1` if it has not been calculated.
+    pub containers: i64,
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Response;
+
+    #[test]
+    fn deserialise_response() {
+        let response: Vec<Response> = serde_json::from_str(
+            r#"[
+                {
+                    "Containers": -1,
+                    "Created": 1588180800,
+                    "Id": "sha256:f70734b6a266dcb5f44c383274821207885b549b75c8e119404917a61335981a",
+                    "Labels": null,
+                    "ParentId": "",
+                    "RepoDigests": [
+                        "alpine@sha256:9a839e63dad54c3a6d1834e29692c8492d93f90c59c978c1ed79109ea4fb9a54"
+                    ],
+                    "RepoTags": ["alpine:latest"],
+                    "SharedSize": -1,
+                    "Size": 5613158,
+                    "VirtualSize": 5613158
+                },
+                {
+                    "Containers": -1,
+                    "Created": 1588180000,
+                    "Id": "sha256:3fd9065eaf02feaf94d68376da52541925650b81698c53c6824d92ff63f98353",
+                    "Labels": {"maintainer": "someone"},
+                    "ParentId": "sha256:f70734b6a266dcb5f44c383274821207885b549b75c8e119404917a61335981a",
+                    "RepoDigests": null,
+                    "RepoTags": null,
+                    "SharedSize": -1,
+                    "Size": 5613200,
+                    "VirtualSize": 5613200
+                }
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(response[0].repo_tags, vec!["alpine:latest"]);
+        assert_eq!(response[0].created.timestamp(), 1_588_180_800);
+        assert_eq!(response[0].parent_id, None);
+        assert_eq!(
+            response[1].parent_id.as_ref().map(String::as_str),
+            Some("sha256:f70734b6a266dcb5f44c383274821207885b549b75c8e119404917a61335981a")
+        );
+        assert!(response[1].repo_tags.is_empty());
+        assert_eq!(response[1].labels["maintainer"], "someone");
+    }
+}
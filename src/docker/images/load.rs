@@ -1,21 +1,21 @@
 use crate::{http_client::HttpClient, Result};
-use futures_util::{
-    future::TryFutureExt,
-    stream::{Stream, TryStreamExt},
-};
+use futures_util::stream::{Stream, TryStreamExt};
 use serde::Deserialize;
 use std::pin::Pin;
-use tokio::io::{AsyncRead, AsyncReadExt};
+use tokio::io::AsyncRead;
 
 /// A request to load an image or images from a tar archive.
 #[allow(missing_debug_implementations)]
 pub struct Load<'a> {
     http_client: &'a HttpClient,
-    tar_archive: Pin<Box<dyn AsyncRead + 'a>>,
+    tar_archive: Pin<Box<dyn AsyncRead + Send>>,
 }
 
 impl<'a> Load<'a> {
-    pub(crate) fn new(http_client: &'a HttpClient, tar_archive: impl AsyncRead + 'a) -> Self {
+    pub(crate) fn new(
+        http_client: &'a HttpClient,
+        tar_archive: impl AsyncRead + Send + 'static,
+    ) -> Self {
         let tar_archive = Box::pin(tar_archive);
 
         Self {
@@ -24,28 +24,16 @@ impl<'a> Load<'a> {
         }
     }
 
-    async fn read_archive(&mut self) -> Result<Vec<u8>> {
-        let mut bytes = Vec::default();
-        self.tar_archive.read_to_end(&mut bytes).await?;
-        Ok(bytes)
-    }
-
     /// Return a representation of the raw stream returned from the docker API.
     ///
     /// this can be used for returning progress updates on the import process.
-    pub fn with_progress(mut self) -> impl Stream<Item = Result<Status>> + 'a {
+    pub fn with_progress(self) -> impl Stream<Item = Result<Status>> + 'a {
         Box::pin(
-            async move {
-                let bytes = self.read_archive().await?;
-
-                Ok(self
-                    .http_client
-                    .post("/images/load")
-                    .tar_body(bytes)
-                    .query(&[("quiet", false)])
-                    .into_stream_json())
-            }
-            .try_flatten_stream(),
+            self.http_client
+                .post("/images/load")
+                .tar_reader_body(self.tar_archive)
+                .query([("quiet", false)])
+                .into_stream_json(),
         )
     }
 
@@ -70,6 +58,7 @@ pub enum Status {
     Complete(Complete),
 }
 
+#[allow(clippy::struct_field_names)]
 #[derive(Debug, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct Progress {
@@ -138,6 +127,6 @@ mod tests {
 
         let complete: Complete = serde_json::from_str(string).unwrap();
 
-        assert_eq!(complete.image(), ("busybox", "latest"))
+        assert_eq!(complete.image(), ("busybox", "latest"));
     }
 }
@@ -0,0 +1,258 @@
+use crate::{http_client::RequestBuilder, Error, Result};
+use futures_util::stream::{Stream, TryStreamExt};
+use serde::Deserialize;
+
+/// A progress message streamed by the Docker host while pulling or pushing an
+/// image
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub struct StatusMessage {
+    /// A description of the current step, such as `Downloading`
+    #[serde(default)]
+    pub status: String,
+
+    /// The progress of the current step, if available
+    #[serde(flatten)]
+    pub progress: Option<Progress>,
+
+    /// The ID of the layer the message refers to, if any
+    pub id: Option<String>,
+}
+
+/// The progress of a step while pulling or pushing an image
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Progress {
+    /// The number of bytes transferred
+    pub progress_detail: ProgressDetail,
+
+    /// A human-readable progress bar
+    pub progress: String,
+}
+
+/// The number of bytes transferred during a step
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+pub struct ProgressDetail {
+    /// The number of bytes transferred so far
+    pub current: u64,
+
+    /// The total number of bytes to transfer, if known
+    #[serde(default)]
+    pub total: Option<u64>,
+}
+
+/// A message in a progress stream, which may report an error.
+///
+/// Docker responds to pulls and pushes with a '200 OK' status before the
+/// operation has completed, so failures are reported in the stream itself.
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum Message {
+    Error(StreamError),
+    Status(StatusMessage),
+}
+
+impl Message {
+    fn into_result(self) -> Result<StatusMessage> {
+        match self {
+            Self::Status(status_message) => Ok(status_message),
+            Self::Error(stream_error) => Err(stream_error.into()),
+        }
+    }
+}
+
+/// An error reported in a stream of JSON messages
+#[derive(Debug, Deserialize)]
+pub(crate) struct StreamError {
+    error: String,
+    #[serde(rename = "errorDetail")]
+    error_detail: Option<ErrorDetail>,
+}
+
+#[derive(Debug, Deserialize)]
+struct ErrorDetail {
+    #[serde(default)]
+    code: Option<i64>,
+    #[serde(default)]
+    message: Option<String>,
+}
+
+impl From<StreamError> for Error {
+    fn from(stream_error: StreamError) -> Self {
+        let StreamError {
+            error,
+            error_detail,
+        } = stream_error;
+
+        let (code, message) = match error_detail {
+            Some(ErrorDetail { code, message }) => (code, message.unwrap_or(error)),
+            None => (None, error),
+        };
+
+        Self::Stream { code, message }
+    }
+}
+
+/// Send the request and return the stream of [`StatusMessage`]s, turning any
+/// errors reported in the stream into [`Error::Stream`]
+pub(crate) fn status_messages(
+    request: RequestBuilder<'_>,
+) -> impl Stream<Item = Result<StatusMessage>> + '_ {
+    request
+        .into_stream_json()
+        .and_then(|message: Message| async move { message.into_result() })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn error_message() {
+        let message: Message = serde_json::from_str(
+            r#"
+            {
+                "errorDetail": {
+                    "message": "manifest for ubuntu:missing not found: manifest unknown"
+                },
+                "error": "manifest for ubuntu:missing not found"
+            }
+            "#,
+        )
+        .unwrap();
+
+        match message.into_result() {
+            Err(Error::Stream { code, message }) => {
+                assert_eq!(code, None);
+                assert_eq!(
+                    message,
+                    "manifest for ubuntu:missing not found: manifest unknown"
+                );
+            }
+            other => panic!("expected an error, got {:?}", other),
+        }
+    }
+
+    #[test]
+    fn status_is_not_an_error() {
+        let message: Message =
+            serde_json::from_str(r#"{"status": "Pulling from library/ubuntu", "id": "18.04"}"#)
+                .unwrap();
+
+        let status_message = message.into_result().unwrap();
+        assert_eq!(status_message.status, "Pulling from library/ubuntu");
+        assert_eq!(
+            status_message.id.as_ref().map(String::as_str),
+            Some("18.04")
+        );
+    }
+
+    mod status_message {
+        use super::*;
+        mod deserialise {
+            use super::*;
+            #[test]
+            fn pulling_from_repo() {
+                let _: StatusMessage = serde_json::from_str(
+                    r#"
+                    {
+                        "status": "Pulling from library/ubuntu",
+                        "id": "10.04"
+                    }
+                    "#,
+                )
+                .unwrap();
+            }
+
+            #[test]
+            fn warning() {
+                let _: StatusMessage = serde_json::from_str(
+                    r#"
+                    {
+                        "status": "Image docker.io/library/ubuntu:10.04 uses outdated schema1 manifest format. Please upgrade to a schema2 image for better future compatibility. More information at https://docs.docker.com/registry/spec/deprecated-schema-v1/",
+                        "id": "10.04"
+                    }
+                    "#
+                ).unwrap();
+            }
+
+            #[test]
+            fn pulling_fs_layer() {
+                let _: StatusMessage = serde_json::from_str(
+                    r#"
+                    {
+                        "status": "Pulling fs layer",
+                        "progressDetail": {},
+                        "id": "a3ed95caeb02"
+                    }
+                    "#,
+                )
+                .unwrap();
+            }
+
+            #[test]
+            fn dowloading() {
+                let _: StatusMessage = serde_json::from_str(
+                    r#"
+                    {
+                        "status": "Downloading",
+                        "progressDetail": {
+                            "current": 32,
+                            "total": 32
+                        },
+                        "progress": "[==================================================>]      32B/32B",
+                        "id": "a3ed95caeb02"
+                    }
+                    "#
+                ).unwrap();
+            }
+
+            #[test]
+            fn extracting() {
+                let _: StatusMessage = serde_json::from_str(
+                    r#"
+                    {
+                        "status": "Extracting",
+                        "progressDetail": {
+                            "current": 32,
+                            "total": 32
+                        },
+                        "progress": "[==================================================>]      32B/32B",
+                        "id": "a3ed95caeb02"
+                    }
+                    "#
+                ).unwrap();
+            }
+
+            #[test]
+            fn push_complete() {
+                let _: StatusMessage = serde_json::from_str(
+                    r#"
+                    {
+                        "progressDetail": {},
+                        "aux": {
+                            "Tag": "latest",
+                            "Digest": "sha256:9a839e63dad54c3a6d1834e29692c8492d93f90c59c978c1ed79109ea4fb9a54",
+                            "Size": 528
+                        }
+                    }
+                    "#,
+                )
+                .unwrap();
+            }
+
+            #[test]
+            fn pulling_complete() {
+                let _: StatusMessage = serde_json::from_str(
+                    r#"
+                    {
+                        "status": "Pulling complete",
+                        "progressDetail": {},
+                        "id": "a3ed95caeb02"
+                    }
+                    "#,
+                )
+                .unwrap();
+            }
+        }
+    }
+}
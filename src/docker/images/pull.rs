@@ -1,6 +1,10 @@
-use crate::{http_client::HttpClient, Result};
-use futures_util::stream::TryStreamExt;
-use serde::{Deserialize, Serialize};
+use crate::{
+    http_client::HttpClient,
+    images::{auth::REGISTRY_AUTH_HEADER, progress, DockerConfig, RegistryAuth, StatusMessage},
+    Result,
+};
+use futures_util::{future::TryFutureExt, stream::TryStreamExt};
+use serde::Serialize;
 use tokio::stream::Stream;
 
 /// A request to pull an image
@@ -42,16 +46,45 @@ use tokio::stream::Stream;
 ///     Ok(())
 /// }
 /// ```
+///
+/// ## Private Registries
+///
+/// ```no_run
+/// use longshoreman::{images::RegistryAuth, Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let images = Docker::new().images();
+///
+///     let auth = RegistryAuth::password("username", "password");
+///
+///     images
+///         .pull("registry.example.com/my-image")
+///         .tag("latest")
+///         .auth(auth)
+///         .send()
+///         .await
+/// }
+/// ```
 #[derive(Debug)]
 pub struct Pull<'a> {
     http_client: &'a HttpClient,
     query: Query<'a>,
+    auth: Option<RegistryAuth>,
+    auth_from_config: bool,
 }
 
 impl<'a> Pull<'a> {
     pub(crate) fn new(http_client: &'a HttpClient, name: &'a str) -> Self {
         let query = Query::new(name);
-        Self { http_client, query }
+        let auth = None;
+        let auth_from_config = false;
+        Self {
+            http_client,
+            query,
+            auth,
+            auth_from_config,
+        }
     }
 
     /// Choose the tag of the image to pull. If unset, *all* tags will be
@@ -62,13 +95,51 @@ impl<'a> Pull<'a> {
         self
     }
 
+    /// The credentials used to authenticate with the registry.
+    ///
+    /// If unset, the image is pulled anonymously.
+    #[must_use]
+    pub fn auth(mut self, auth: RegistryAuth) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+
+    /// Read the credentials for the registry from the Docker config file of
+    /// the current user (see [`DockerConfig`]) when none are given with
+    /// [`auth`](Self::auth).
+    ///
+    /// This may run a credential helper. If no credentials are stored for the
+    /// registry, the image is pulled anonymously. The default is 'false'.
+    #[must_use]
+    pub fn auth_from_config(mut self, auth_from_config: bool) -> Self {
+        self.auth_from_config = auth_from_config;
+        self
+    }
+
     /// Consume the request and return a stream. The stream returns a sequence
     /// of progress messages.
     pub fn stream(self) -> impl Stream<Item = Result<StatusMessage>> + 'a {
-        self.http_client
-            .post("/images/create")
-            .query(self.query)
-            .into_stream_json()
+        async move {
+            let auth = match self.auth {
+                Some(auth) => Some(auth),
+                None if self.auth_from_config => {
+                    DockerConfig::load()
+                        .await?
+                        .resolve_image(self.query.from_image)
+                        .await?
+                }
+                None => None,
+            };
+
+            let mut request = self.http_client.post("/images/create").query(self.query);
+
+            if let Some(auth) = auth {
+                request = request.header(REGISTRY_AUTH_HEADER, auth.header_value());
+            }
+
+            Ok(progress::status_messages(request))
+        }
+        .try_flatten_stream()
     }
 
     /// Consume the request and return a future that resolves when the image
@@ -98,122 +169,3 @@ impl<'a> Query<'a> {
         }
     }
 }
-
-#[derive(Debug, Deserialize)]
-pub struct StatusMessage {
-    status: String,
-    #[serde(flatten)]
-    progress: Option<Progress>,
-    id: Option<String>,
-}
-
-#[derive(Debug, Deserialize)]
-#[serde(rename_all = "camelCase")]
-struct Progress {
-    progress_detail: ProgressDetail,
-    progress: String,
-}
-
-#[derive(Debug, Deserialize)]
-struct ProgressDetail {
-    current: u32,
-    total: u32,
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    mod status_message {
-        use super::*;
-        mod deserialise {
-            use super::*;
-            #[test]
-            fn pulling_from_repo() {
-                let _: StatusMessage = serde_json::from_str(
-                    r#"
-                    {
-                        "status": "Pulling from library/ubuntu",
-                        "id": "10.04"
-                    }
-                    "#,
-                )
-                .unwrap();
-            }
-
-            #[test]
-            fn warning() {
-                let _: StatusMessage = serde_json::from_str(
-                    r#"
-                    {
-                        "status": "Image docker.io/library/ubuntu:10.04 uses outdated schema1 manifest format. Please upgrade to a schema2 image for better future compatibility. More information at https://docs.docker.com/registry/spec/deprecated-schema-v1/",
-                        "id": "10.04"
-                    }
-                    "#
-                ).unwrap();
-            }
-
-            #[test]
-            fn pulling_fs_layer() {
-                let _: StatusMessage = serde_json::from_str(
-                    r#"
-                    {
-                        "status": "Pulling fs layer",
-                        "progressDetail": {},
-                        "id": "a3ed95caeb02"
-                    }
-                    "#,
-                )
-                .unwrap();
-            }
-
-            #[test]
-            fn dowloading() {
-                let _: StatusMessage = serde_json::from_str(
-                    r#"
-                    {
-                        "status": "Downloading",
-                        "progressDetail": {
-                            "current": 32,
-                            "total": 32
-                        },
-                        "progress": "[==================================================>]      32B/32B",
-                        "id": "a3ed95caeb02"
-                    }
-                    "#
-                ).unwrap();
-            }
-
-            #[test]
-            fn extracting() {
-                let _: StatusMessage = serde_json::from_str(
-                    r#"
-                    {
-                        "status": "Extracting",
-                        "progressDetail": {
-                            "current": 32,
-                            "total": 32
-                        },
-                        "progress": "[==================================================>]      32B/32B",
-                        "id": "a3ed95caeb02"
-                    }
-                    "#
-                ).unwrap();
-            }
-
-            #[test]
-            fn pulling_complete() {
-                let _: StatusMessage = serde_json::from_str(
-                    r#"
-                    {
-                        "status": "Pulling complete",
-                        "progressDetail": {},
-                        "id": "a3ed95caeb02"
-                    }
-                    "#,
-                )
-                .unwrap();
-            }
-        }
-    }
-}
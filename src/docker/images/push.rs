@@ -0,0 +1,131 @@
+use crate::{
+    http_client::HttpClient,
+    images::{auth::REGISTRY_AUTH_HEADER, progress, DockerConfig, RegistryAuth, StatusMessage},
+    Result,
+};
+use futures_util::{future::TryFutureExt, stream::TryStreamExt};
+use serde::Serialize;
+use tokio::stream::Stream;
+
+/// A request to push an image to a registry
+///
+/// The image must first be tagged with the name of the registry, such as
+/// `registry.example.com/my-image`.
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{images::RegistryAuth, Docker, Result};
+/// use tokio::stream::StreamExt;
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let images = Docker::new().images();
+///
+///     // Push an image and stream the progress
+///     let mut stream = Box::pin(
+///         images
+///             .push("registry.example.com/my-image")
+///             .tag("latest")
+///             .auth(RegistryAuth::identity_token("TOKEN"))
+///             .stream(),
+///     );
+///     while let Some(status_message) = stream.next().await {
+///         println!("{:#?}", status_message?);
+///     }
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Push<'a> {
+    http_client: &'a HttpClient,
+    name: &'a str,
+    query: Query<'a>,
+    auth: Option<RegistryAuth>,
+    auth_from_config: bool,
+}
+
+impl<'a> Push<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, name: &'a str) -> Self {
+        let query = Query::default();
+        let auth = None;
+        let auth_from_config = false;
+        Self {
+            http_client,
+            name,
+            query,
+            auth,
+            auth_from_config,
+        }
+    }
+
+    /// Choose the tag of the image to push. If unset, *all* tags will be
+    /// pushed.
+    #[must_use]
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.query.tag = Some(tag);
+        self
+    }
+
+    /// The credentials used to authenticate with the registry.
+    ///
+    /// If unset, the image is pushed anonymously.
+    #[must_use]
+    pub fn auth(mut self, auth: RegistryAuth) -> Self {
+        self.auth = Some(auth);
+        self
+    }
+
+    /// Read the credentials for the registry from the Docker config file of
+    /// the current user (see [`DockerConfig`]) when none are given with
+    /// [`auth`](Self::auth).
+    ///
+    /// This may run a credential helper. If no credentials are stored for the
+    /// registry, the image is pushed anonymously. The default is 'false'.
+    #[must_use]
+    pub fn auth_from_config(mut self, auth_from_config: bool) -> Self {
+        self.auth_from_config = auth_from_config;
+        self
+    }
+
+    /// Consume the request and return a stream. The stream returns a sequence
+    /// of progress messages.
+    pub fn stream(self) -> impl Stream<Item = Result<StatusMessage>> + 'a {
+        async move {
+            let auth = match self.auth {
+                Some(auth) => auth,
+                None if self.auth_from_config => DockerConfig::load()
+                    .await?
+                    .resolve_image(self.name)
+                    .await?
+                    .unwrap_or_default(),
+                None => RegistryAuth::default(),
+            };
+
+            let endpoint = format!("/images/{}/push", self.name);
+            let request = self
+                .http_client
+                .post(endpoint)
+                .header(REGISTRY_AUTH_HEADER, auth.header_value())
+                .query(self.query);
+
+            Ok(progress::status_messages(request))
+        }
+        .try_flatten_stream()
+    }
+
+    /// Consume the request and return a future that resolves when the image
+    /// push is complete
+    pub async fn send(self) -> Result<()> {
+        let stream = self.stream();
+
+        let result: Result<Vec<StatusMessage>> = stream.try_collect().await;
+
+        result.map(|_| ())
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Query<'a> {
+    tag: Option<&'a str>,
+}
@@ -0,0 +1,118 @@
+use crate::{http_client::HttpClient, Result};
+use serde::{Deserialize, Serialize};
+
+/// A request to remove an image
+///
+/// # Example
+/// ```no_run
+/// use longshoreman::{images::RemoveResponse, Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let removed = Docker::new()
+///         .images()
+///         .remove("alpine:latest")
+///         .force(true)
+///         .send()
+///         .await?;
+///
+///     for entry in removed {
+///         match entry {
+///             RemoveResponse::Untagged(tag) => println!("untagged: {}", tag),
+///             RemoveResponse::Deleted(id) => println!("deleted: {}", id),
+///         }
+///     }
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Remove<'a> {
+    http_client: &'a HttpClient,
+    name: &'a str,
+    query: Query,
+}
+
+impl<'a> Remove<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, name: &'a str) -> Self {
+        let query = Query::default();
+        Self {
+            http_client,
+            name,
+            query,
+        }
+    }
+
+    /// Remove the image even if it is being used by stopped containers or has
+    /// other tags.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn force(mut self, force: bool) -> Self {
+        self.query.force = force;
+        self
+    }
+
+    /// Do not delete untagged parent images.
+    ///
+    /// default is 'false'.
+    #[must_use]
+    pub fn no_prune(mut self, no_prune: bool) -> Self {
+        self.query.noprune = no_prune;
+        self
+    }
+
+    /// Consume the request and return the tags which were removed and the
+    /// images which were deleted
+    pub async fn send(self) -> Result<Vec<Response>> {
+        let endpoint = format!("/images/{}", self.name);
+        self.http_client
+            .delete(&endpoint)
+            .query(self.query)
+            .into_json()
+            .await
+    }
+}
+
+#[derive(Debug, Default, Serialize)]
+struct Query {
+    force: bool,
+    noprune: bool,
+}
+
+/// An image tag which was removed, or an image which was deleted
+#[derive(Debug, Clone, PartialEq, Eq, Deserialize)]
+pub enum Response {
+    /// A tag which was removed from the image
+    Untagged(String),
+
+    /// The ID of an image which was deleted
+    Deleted(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::Response;
+
+    #[test]
+    fn deserialise_response() {
+        let response: Vec<Response> = serde_json::from_str(
+            r#"[
+                {"Untagged": "alpine:latest"},
+                {"Deleted": "sha256:f70734b6a266dcb5f44c383274821207885b549b75c8e119404917a61335981a"}
+            ]"#,
+        )
+        .unwrap();
+
+        assert_eq!(
+            response,
+            vec![
+                Response::Untagged("alpine:latest".into()),
+                Response::Deleted(
+                    "sha256:f70734b6a266dcb5f44c383274821207885b549b75c8e119404917a61335981a"
+                        .into()
+                ),
+            ]
+        );
+    }
+}
@@ -0,0 +1,91 @@
+use crate::{http_client::HttpClient, Result};
+use futures_util::stream::{Stream, TryStreamExt};
+use hyper::body::Bytes;
+use std::io;
+use tokio::io::{stream_reader, AsyncRead};
+
+/// A request to export one or more images as a tar archive
+///
+/// The archive can be loaded into another Docker host with
+/// [`Images::load`](crate::images::Images::load).
+///
+/// # Example
+///
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     let images = Docker::new().images();
+///
+///     // copy the archive to disk without buffering it in memory
+///     let mut archive = Box::pin(images.save("ubuntu:18.04").image("alpine:latest").reader());
+///     let mut file = tokio::fs::File::create("images.tar").await?;
+///     tokio::io::copy(&mut archive, &mut file).await?;
+///
+///     Ok(())
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Save<'a> {
+    http_client: &'a HttpClient,
+    names: Vec<&'a str>,
+}
+
+impl<'a> Save<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, name: &'a str) -> Self {
+        let names = vec![name];
+        Self { http_client, names }
+    }
+
+    /// Add another image to the archive.
+    ///
+    /// The 'name' parameter may be a name, a name and tag, or an id. Images
+    /// which share layers are exported together, so the shared layers are
+    /// only included once.
+    #[must_use]
+    pub fn image(mut self, name: &'a str) -> Self {
+        self.names.push(name);
+        self
+    }
+
+    /// Consume the request and return the tar archive as a stream of bytes
+    pub fn stream(self) -> impl Stream<Item = Result<Bytes>> + 'a {
+        let request = match self.names.as_slice() {
+            [name] => self.http_client.get(format!("/images/{}/get", name)),
+            names => self
+                .http_client
+                .get("/images/get")
+                .query(names_query(names)),
+        };
+
+        request.into_stream()
+    }
+
+    /// Consume the request and return the tar archive as an [`AsyncRead`]
+    #[must_use]
+    pub fn reader(self) -> impl AsyncRead + 'a {
+        stream_reader(
+            self.stream()
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e)),
+        )
+    }
+}
+
+/// The `names` parameter is repeated for each image
+fn names_query<'a>(names: &[&'a str]) -> Vec<(&'static str, &'a str)> {
+    names.iter().map(|name| ("names", *name)).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::names_query;
+
+    #[test]
+    fn serialize_names() {
+        assert_eq!(
+            serde_urlencoded::to_string(names_query(&["ubuntu:18.04", "alpine"])).unwrap(),
+            "names=ubuntu%3A18.04&names=alpine"
+        );
+    }
+}
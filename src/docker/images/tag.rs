@@ -0,0 +1,63 @@
+use crate::{http_client::HttpClient, Result};
+use serde::Serialize;
+
+/// A request to tag an image, so that it can be pushed to a repository
+///
+/// # Example
+/// ```no_run
+/// use longshoreman::{Docker, Result};
+///
+/// #[tokio::main]
+/// async fn main() -> Result<()> {
+///     Docker::new()
+///         .images()
+///         .tag("alpine:latest", "registry.example.com/alpine")
+///         .tag("3.11")
+///         .send()
+///         .await
+/// }
+/// ```
+#[derive(Debug)]
+pub struct Tag<'a> {
+    http_client: &'a HttpClient,
+    name: &'a str,
+    query: Query<'a>,
+}
+
+impl<'a> Tag<'a> {
+    pub(crate) fn new(http_client: &'a HttpClient, name: &'a str, repo: &'a str) -> Self {
+        let query = Query { repo, tag: None };
+        Self {
+            http_client,
+            name,
+            query,
+        }
+    }
+
+    /// The name of the new tag.
+    ///
+    /// default is 'latest'.
+    #[must_use]
+    pub fn tag(mut self, tag: &'a str) -> Self {
+        self.query.tag = Some(tag);
+        self
+    }
+
+    /// Consume the request builder and send the request to the Docker host
+    pub async fn send(self) -> Result<()> {
+        let endpoint = format!("/images/{}/tag", self.name);
+        self.http_client
+            .post(endpoint)
+            .query(self.query)
+            .into_status()
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[derive(Debug, Serialize)]
+struct Query<'a> {
+    repo: &'a str,
+    tag: Option<&'a str>,
+}
@@ -25,7 +25,7 @@ impl Volumes {
 
     /// Create a new Docker volume
     #[must_use]
-    pub fn create(&self) -> Create {
+    pub fn create(&self) -> Create<'_> {
         Create::new(&self.http_client)
     }
 
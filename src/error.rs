@@ -48,6 +48,22 @@ pub enum Error {
     #[error("expected the docker host to upgrade the HTTP connection but it did not")]
     ConnectionNotUpgraded,
 
+    /// Errors reported by the docker host part-way through a streamed
+    /// response, such as a failed image pull
+    #[error("{message}")]
+    Stream {
+        /// The error code, if one was given
+        code: Option<i64>,
+
+        /// A descriptive string
+        message: String,
+    },
+
+    /// Errors reading registry credentials from a Docker config file or a
+    /// credential helper
+    #[error("failed to read registry credentials: {0}")]
+    Credentials(String),
+
     /// Errors that occur when decoding byte streams
     #[error("failed to decode bytes")]
     Decode,
@@ -1,13 +1,13 @@
 //! Transports for communicating with the docker daemon
 
-use hyper::Method;
+use hyper::{Body, Method};
 use std::path::PathBuf;
 
 mod transport;
 use transport::Transport;
 
 mod request;
-use request::RequestBuilder;
+pub(crate) use request::RequestBuilder;
 
 #[derive(Debug)]
 pub(crate) enum HttpClient {
@@ -29,21 +29,25 @@ impl HttpClient {
         Self::Uds(transport)
     }
 
-    fn request(&self, endpoint: impl AsRef<str>) -> RequestBuilder {
+    fn request(&self, endpoint: impl AsRef<str>) -> RequestBuilder<'_> {
         RequestBuilder::new(self, endpoint)
     }
 
-    pub fn get(&self, endpoint: impl AsRef<str>) -> RequestBuilder {
+    pub fn get(&self, endpoint: impl AsRef<str>) -> RequestBuilder<'_> {
         self.request(endpoint).method(Method::GET)
     }
 
-    pub fn post(&self, endpoint: impl AsRef<str>) -> RequestBuilder {
+    pub fn post(&self, endpoint: impl AsRef<str>) -> RequestBuilder<'_> {
         self.request(endpoint).method(Method::POST)
     }
 
-    /*     pub fn put(&self, endpoint: impl AsRef<str>) -> RequestBuilder {
+    pub fn put(&self, endpoint: impl AsRef<str>) -> RequestBuilder<'_> {
         self.request(endpoint).method(Method::PUT)
-    } */
+    }
+
+    pub fn head(&self, endpoint: impl AsRef<str>) -> RequestBuilder<'_> {
+        self.request(endpoint).method(Method::HEAD)
+    }
 
     pub fn delete<'a>(&'a self, endpoint: &'a str) -> RequestBuilder<'a> {
         self.request(endpoint).method(Method::DELETE)
@@ -69,7 +73,7 @@ impl HttpClient {
 
 pub enum BodyType {
     Json(Vec<u8>),
-    Tar(Vec<u8>),
+    Tar(Body),
 }
 
 impl BodyType {
@@ -77,8 +81,8 @@ impl BodyType {
         Self::Json(data)
     }
 
-    fn tar(data: Vec<u8>) -> Self {
-        Self::Tar(data)
+    fn tar(data: impl Into<Body>) -> Self {
+        Self::Tar(data.into())
     }
 
     fn mime(&self) -> String {
@@ -88,9 +92,10 @@ impl BodyType {
         }
     }
 
-    fn into_data(self) -> Vec<u8> {
+    fn into_body(self) -> Body {
         match self {
-            Self::Json(data) | Self::Tar(data) => data,
+            Self::Json(data) => data.into(),
+            Self::Tar(body) => body,
         }
     }
 }
@@ -1,15 +1,24 @@
 use super::{BodyType, HttpClient};
 use crate::{Error, Result};
+use bytes::BytesMut;
 use futures_util::{
-    future::TryFutureExt,
+    future::{self, TryFutureExt},
     stream::{Stream, StreamExt, TryStreamExt},
 };
 use hyper::{
     body::{to_bytes, Bytes},
+    header::{self, HeaderMap, HeaderName, HeaderValue},
+    upgrade::Upgraded,
     Body, Method, Request, StatusCode,
 };
 use serde::{Deserialize, Serialize};
-use std::convert::TryFrom;
+use std::{convert::TryFrom, io};
+use tokio::io::{stream_reader, AsyncRead};
+use tokio_util::codec::{BytesCodec, Decoder, FramedRead, LinesCodec};
+
+/// The longest line accepted in a stream of JSON objects, so that a Docker
+/// host which never sends a newline can't grow the buffer without bound
+const MAX_JSON_LINE_LENGTH: usize = 8 * 1024 * 1024;
 
 /// A builder API for constructing and sending an HTTP request to the Docker
 /// host.
@@ -61,11 +70,19 @@ impl<'a> RequestBuilder<'a> {
     }
 
     /// Add a custom header to the request
-    /*     pub fn header(mut self, key: impl IntoHeaderName, value: &str) -> Self {
-        let value = http::header::HeaderValue::from_str(value).unwrap();
-        self.builder.headers_mut().unwrap().append(key, value);
+    ///
+    /// Invalid header names or values will cause an error when the request is
+    /// sent.
+    pub fn header<K, V>(mut self, key: K, value: V) -> Self
+    where
+        HeaderName: TryFrom<K>,
+        <HeaderName as TryFrom<K>>::Error: Into<http::Error>,
+        HeaderValue: TryFrom<V>,
+        <HeaderValue as TryFrom<V>>::Error: Into<http::Error>,
+    {
+        self.builder = self.builder.header(key, value);
         self
-    } */
+    }
 
     /// Add a form-encoded query to the request.
     ///
@@ -90,11 +107,30 @@ impl<'a> RequestBuilder<'a> {
     /// Add a tar archive as the body of the request
     ///
     /// This method will automatically set the content type header
-    pub fn tar_body(mut self, data: Vec<u8>) -> Self {
-        self.body = Some(BodyType::tar(data));
+    pub fn tar_body(mut self, body: impl Into<Body>) -> Self {
+        self.body = Some(BodyType::tar(body));
         self
     }
 
+    /// Add a stream of chunks of a tar archive as the body of the request
+    ///
+    /// This method will automatically set the content type header
+    pub fn tar_stream_body<S, E>(self, stream: S) -> Self
+    where
+        S: Stream<Item = std::result::Result<Bytes, E>> + Send + 'static,
+        E: std::error::Error + Send + Sync + 'static,
+    {
+        self.tar_body(Body::wrap_stream(stream))
+    }
+
+    /// Add a tar archive read from an [`AsyncRead`] as the body of the
+    /// request
+    ///
+    /// This method will automatically set the content type header
+    pub fn tar_reader_body(self, reader: impl AsyncRead + Send + 'static) -> Self {
+        self.tar_stream_body(FramedRead::new(reader, BytesCodec::new()).map_ok(BytesMut::freeze))
+    }
+
     // Finalisers
 
     /// Build the request
@@ -111,8 +147,7 @@ impl<'a> RequestBuilder<'a> {
         let request = match self.body {
             Some(body_type) => {
                 let mime = http::HeaderValue::try_from(body_type.mime())?;
-                let data = body_type.into_data();
-                let body = Body::from(data);
+                let body = body_type.into_body();
 
                 builder.headers_mut().unwrap().append("content-type", mime);
                 builder.body(body)?
@@ -135,43 +170,47 @@ impl<'a> RequestBuilder<'a> {
     /// an error, as required.
     async fn into_body(self) -> Result<Body> {
         let response = self.into_response().await?;
-        let status = response.status();
-
-        match status {
-            // Success case: pass on the response
-            StatusCode::OK
-            | StatusCode::CREATED
-            | StatusCode::SWITCHING_PROTOCOLS
-            | StatusCode::NO_CONTENT => Ok(response.into_body()),
-            // Error case: parse the text
-            _ => {
-                let bytes = to_bytes(response.into_body()).await?;
-
-                Err(Error::Fault {
-                    code: status,
-                    message: get_error_message(&bytes).unwrap_or_else(|_| {
-                        status
-                            .canonical_reason()
-                            .unwrap_or("unknown error code")
-                            .to_owned()
-                    }),
-                })
-            }
+        Ok(check_status(response).await?.into_body())
+    }
+
+    /// Send the request and return the status code of the response.
+    ///
+    /// Unlike the other finalisers, a '304 Not Modified' response is not
+    /// treated as an error, since some endpoints use it to signal that the
+    /// request had no effect.
+    pub async fn into_status(self) -> Result<StatusCode> {
+        let response = self.into_response().await?;
+
+        match response.status() {
+            StatusCode::NOT_MODIFIED => Ok(StatusCode::NOT_MODIFIED),
+            _ => Ok(check_status(response).await?.status()),
         }
     }
 
+    /// Send the request and return the headers of the response.
+    ///
+    /// This is useful for `HEAD` requests, where the response has no body.
+    pub async fn into_headers(self) -> Result<HeaderMap> {
+        let response = self.into_response().await?;
+        Ok(check_status(response).await?.headers().clone())
+    }
+
     /// Upgrade the HTTP connection into a duplex stream
-    /*     pub async fn upgrade(mut self) -> Result<impl AsyncRead + AsyncWrite> {
-        self = self.header(hyper::header::CONNECTION, "Upgrade");
-        self = self.header(hyper::header::UPGRADE, "tcp");
+    pub async fn upgrade(mut self) -> Result<Upgraded> {
+        self = self.header(header::CONNECTION, "Upgrade");
+        self = self.header(header::UPGRADE, "tcp");
 
         let hyper_response = self.into_response().await?;
 
         match hyper_response.status() {
             StatusCode::SWITCHING_PROTOCOLS => Ok(hyper_response.into_body().on_upgrade().await?),
-            _ => Err(Error::ConnectionNotUpgraded),
+            // Surface any error returned by the docker host
+            _ => match check_status(hyper_response).await {
+                Ok(_) => Err(Error::ConnectionNotUpgraded),
+                Err(e) => Err(e),
+            },
         }
-    } */
+    }
 
     /// Send the request and concatenate the response into a Bytes object
     async fn into_bytes(self) -> Result<Bytes> {
@@ -196,49 +235,111 @@ impl<'a> RequestBuilder<'a> {
 
     /// Send the request, and return the response body as a stream of bytes
     pub fn into_stream(self) -> impl Stream<Item = Result<Bytes>> + 'a {
-        async fn unfold(mut body: Body) -> Option<(Result<Bytes>, Body)> {
-            let bytes_result = body.next().await?.map_err(Error::from);
-
-            Some((bytes_result, body))
-        }
-
         async move {
             let body = self.into_body().await?;
-            Ok(futures_util::stream::unfold(body, unfold))
+            Ok(body_stream(body))
         }
         .try_flatten_stream()
     }
 
+    /// Send the request, and return the headers of the response along with
+    /// the response body as a stream of bytes
+    pub async fn into_headers_and_stream(
+        self,
+    ) -> Result<(HeaderMap, impl Stream<Item = Result<Bytes>>)> {
+        let response = check_status(self.into_response().await?).await?;
+        let (parts, body) = response.into_parts();
+
+        Ok((parts.headers, body_stream(body)))
+    }
+
     /// Send the request, and deserialize the returned stream of JSON into a
     /// stream of objects.
     pub fn into_stream_json<T>(self) -> impl Stream<Item = Result<T>> + 'a
     where
-        for<'de> T: Deserialize<'de>,
+        for<'de> T: Deserialize<'de> + 'a,
     {
-        let byte_stream = self.into_stream();
-
-        byte_stream.and_then(|bytes| async move { Ok(serde_json::from_slice(&bytes)?) })
+        json_lines(self.into_stream())
     }
 
-    /*     /// Send the request, and deserialize the returned stream using the given
-    /// codec
-    pub fn decode<T, C, I, E>(self, codec: C) -> impl Stream<Item = Result<T>> + 'a
+    /// Send the request, and decode the returned stream of bytes into a stream
+    /// of frames using the given codec
+    pub fn decode<C, E>(self, codec: C) -> impl Stream<Item = Result<C::Item>> + 'a
     where
-        for<'de> T: Deserialize<'de>,
-        C: Decoder<Item = I, Error = E> + 'a,
-        I: AsRef<[u8]> + 'a,
+        C: Decoder<Error = E> + 'a,
         Error: From<E>,
-        E: From<std::io::Error> + 'a,
+        E: From<io::Error> + 'a,
     {
-        let stream = self
-            .into_stream()
-            .map_err(|e| io::Error::new(io::ErrorKind::Other, e));
-
-        let reader = stream_reader(stream);
-        FramedRead::new(reader, codec)
-            .map_err(Error::from)
-            .and_then(|bytes| async move { Ok(serde_json::from_slice(bytes.as_ref())?) })
-    } */
+        framed(self.into_stream(), codec)
+    }
+}
+
+/// Decode a stream of bytes into a stream of frames using the given codec
+fn framed<S, C, E>(stream: S, codec: C) -> impl Stream<Item = Result<C::Item>>
+where
+    S: Stream<Item = Result<Bytes>>,
+    C: Decoder<Error = E>,
+    Error: From<E>,
+    E: From<io::Error>,
+{
+    let reader = stream_reader(stream.map_err(|e| io::Error::new(io::ErrorKind::Other, e)));
+    FramedRead::new(reader, codec).map_err(Error::from)
+}
+
+/// Deserialize a stream of newline-delimited JSON objects.
+///
+/// A single object may be split over several chunks, so the stream is framed
+/// by lines rather than by chunks.
+fn json_lines<T, S>(stream: S) -> impl Stream<Item = Result<T>>
+where
+    for<'de> T: Deserialize<'de>,
+    S: Stream<Item = Result<Bytes>>,
+{
+    framed(
+        stream,
+        LinesCodec::new_with_max_length(MAX_JSON_LINE_LENGTH),
+    )
+    .try_filter(|line| future::ready(!line.trim().is_empty()))
+    .and_then(|line| async move { Ok(serde_json::from_str(&line)?) })
+}
+
+/// Convert a response body into a stream of bytes
+fn body_stream(body: Body) -> impl Stream<Item = Result<Bytes>> {
+    async fn unfold(mut body: Body) -> Option<(Result<Bytes>, Body)> {
+        let bytes_result = body.next().await?.map_err(Error::from);
+
+        Some((bytes_result, body))
+    }
+
+    futures_util::stream::unfold(body, unfold)
+}
+
+/// Check the status code of a response, and convert it into an error if
+/// required.
+async fn check_status(response: hyper::Response<Body>) -> Result<hyper::Response<Body>> {
+    let status = response.status();
+
+    match status {
+        // Success case: pass on the response
+        StatusCode::OK
+        | StatusCode::CREATED
+        | StatusCode::SWITCHING_PROTOCOLS
+        | StatusCode::NO_CONTENT => Ok(response),
+        // Error case: parse the text
+        _ => {
+            let bytes = to_bytes(response.into_body()).await?;
+
+            Err(Error::Fault {
+                code: status,
+                message: get_error_message(&bytes).unwrap_or_else(|_| {
+                    status
+                        .canonical_reason()
+                        .unwrap_or("unknown error code")
+                        .to_owned()
+                }),
+            })
+        }
+    }
 }
 
 fn get_error_message(bytes: impl AsRef<[u8]>) -> Result<String> {
@@ -250,3 +351,59 @@ fn get_error_message(bytes: impl AsRef<[u8]>) -> Result<String> {
     let error_response: ErrorResponse = serde_json::from_slice(bytes.as_ref())?;
     Ok(error_response.message)
 }
+
+#[cfg(test)]
+mod tests {
+    use super::{json_lines, MAX_JSON_LINE_LENGTH};
+    use crate::{Error, Result};
+    use futures_util::stream::{self, Stream, TryStreamExt};
+    use hyper::body::Bytes;
+    use serde::Deserialize;
+
+    #[derive(Debug, PartialEq, Eq, Deserialize)]
+    struct Message {
+        status: String,
+    }
+
+    fn chunks(chunks: Vec<&'static str>) -> impl Stream<Item = Result<Bytes>> {
+        stream::iter(chunks.into_iter().map(|chunk| Ok(Bytes::from(chunk))))
+    }
+
+    #[tokio::test]
+    async fn object_split_across_chunks() {
+        let messages: Vec<Message> = json_lines(chunks(vec![
+            "{\"status\": \"Pull",
+            "ing\"}\r\n\n{\"status\"",
+            ": \"Done\"}",
+        ]))
+        .try_collect()
+        .await
+        .unwrap();
+
+        assert_eq!(
+            messages,
+            vec![
+                Message {
+                    status: "Pulling".into()
+                },
+                Message {
+                    status: "Done".into()
+                }
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn line_too_long() {
+        let line = Bytes::from("x".repeat(MAX_JSON_LINE_LENGTH + 1));
+
+        let result: Result<Vec<Message>> = json_lines(stream::once(async { Ok(line) }))
+            .try_collect()
+            .await;
+
+        match result {
+            Err(Error::Decode) => {}
+            other => panic!("expected a decode error, got {:?}", other),
+        }
+    }
+}
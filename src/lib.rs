@@ -4,13 +4,18 @@
 
 #![deny(clippy::all, missing_docs, missing_debug_implementations)]
 #![warn(clippy::pedantic)]
+// every endpoint fails in the same ways (transport errors, error responses from
+// the Docker host, and responses that can't be decoded), which are documented
+// once on `Error` rather than repeated on each method
+#![allow(clippy::missing_errors_doc)]
 //#![allow(dead_code)]
 
+mod blocking;
 mod docker;
 mod error;
 mod http_client;
 
-pub use docker::{containers, images, volumes, Docker};
+pub use docker::{containers, exec, images, volumes, Docker, Filters};
 pub use error::{Error, Result};
 
 mod utils;
@@ -1,4 +1,5 @@
-use serde::Deserialize;
+use serde::{de::Error, Deserialize};
+use std::{collections::BTreeMap, fmt::Display, str::FromStr};
 
 pub fn empty_string_as_none<'de, D>(de: D) -> Result<Option<String>, D::Error>
 where
@@ -7,3 +8,69 @@ where
     let opt: Option<String> = Option::deserialize(de)?;
     Ok(opt.filter(|s| !s.is_empty()))
 }
+
+/// Parse a string into a value, treating the empty string as `None`
+pub fn parse_non_empty<'de, D, T>(de: D) -> Result<Option<T>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: FromStr,
+    T::Err: Display,
+{
+    empty_string_as_none(de)?
+        .map(|s| s.parse().map_err(D::Error::custom))
+        .transpose()
+}
+
+/// Docker represents empty lists and maps as `null`. This deserialises `null`
+/// as the default value of the type
+pub fn null_as_default<'de, D, T>(de: D) -> Result<T, D::Error>
+where
+    D: serde::Deserializer<'de>,
+    T: Default + Deserialize<'de>,
+{
+    let opt: Option<T> = Option::deserialize(de)?;
+    Ok(opt.unwrap_or_default())
+}
+
+/// Docker represents sets as maps with empty values (such as
+/// `{"80/tcp": {}}`). This deserialises the keys of such a map
+pub fn map_keys<'de, D>(de: D) -> Result<Vec<String>, D::Error>
+where
+    D: serde::Deserializer<'de>,
+{
+    let opt: Option<BTreeMap<String, serde_json::Value>> = Option::deserialize(de)?;
+    Ok(opt
+        .unwrap_or_default()
+        .into_iter()
+        .map(|(k, _)| k)
+        .collect())
+}
+
+/// (De)serialise an optional [`std::time::Duration`] as an integer number of
+/// nanoseconds
+pub mod duration_nanos {
+    use serde::{Deserialize, Deserializer, Serializer};
+    use std::{convert::TryFrom, time::Duration};
+
+    #[allow(clippy::ref_option)]
+    pub fn serialize<S>(duration: &Option<Duration>, ser: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match duration {
+            Some(duration) => {
+                let nanos = u64::try_from(duration.as_nanos()).unwrap_or(std::u64::MAX);
+                ser.serialize_some(&nanos)
+            }
+            None => ser.serialize_none(),
+        }
+    }
+
+    pub fn deserialize<'de, D>(de: D) -> Result<Option<Duration>, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let nanos: Option<u64> = Option::deserialize(de)?;
+        Ok(nanos.filter(|&n| n > 0).map(Duration::from_nanos))
+    }
+}
@@ -0,0 +1,41 @@
+use longshoreman::{Docker, Result};
+
+#[tokio::test]
+async fn test() -> Result<()> {
+    let docker = Docker::new();
+    let images = docker.images();
+    let containers = docker.containers();
+
+    let image = "alpine";
+
+    // Pull image
+    images.pull(image).tag("latest").send().await?;
+
+    // Create a container
+    let id = containers.create(image).send().await?.id;
+
+    // Copy a file in
+    containers.copy_into(&id, "Cargo.toml", "/tmp").await?;
+    let stat = containers
+        .archive_stat(&id, "/tmp/Cargo.toml")
+        .send()
+        .await?;
+    assert_eq!(stat.name, "Cargo.toml");
+    assert!(!stat.is_dir());
+
+    // and back out again
+    let destination = std::env::temp_dir().join(format!("longshoreman-{}", id));
+    std::fs::create_dir_all(&destination)?;
+    containers
+        .copy_from(&id, "/tmp/Cargo.toml", &destination)
+        .await?;
+    assert_eq!(
+        std::fs::read(destination.join("Cargo.toml"))?,
+        std::fs::read("Cargo.toml")?
+    );
+    std::fs::remove_dir_all(&destination)?;
+
+    containers.remove(&id).send().await?;
+
+    Ok(())
+}
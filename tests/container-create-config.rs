@@ -0,0 +1,46 @@
+use longshoreman::{containers::RestartPolicy, Docker, Result};
+
+#[tokio::test]
+async fn test() -> Result<()> {
+    let docker = Docker::new();
+    let images = docker.images();
+    let containers = docker.containers();
+
+    let image = "alpine";
+
+    // Pull image
+    images.pull(image).tag("latest").send().await?;
+
+    // Create a container with a custom configuration
+    let id = containers
+        .create(image)
+        .cmd(vec!["sh", "-c", "echo hello"])
+        .env("KEY", "value")
+        .working_dir("/tmp")
+        .label("com.example.key", "value")
+        .memory(64 * 1024 * 1024)
+        .restart_policy(RestartPolicy::on_failure(3))
+        .send()
+        .await?
+        .id;
+
+    // Inspect it, and check the configuration was applied
+    let response = containers.inspect(&id).send().await?;
+
+    assert_eq!(response.config.cmd, vec!["sh", "-c", "echo hello"]);
+    assert!(response.config.env.contains(&"KEY=value".to_owned()));
+    assert_eq!(response.config.working_dir, Some("/tmp".to_owned()));
+    assert_eq!(response.config.labels["com.example.key"], "value");
+    assert_eq!(
+        response.host_config.resources.memory,
+        Some(64 * 1024 * 1024)
+    );
+    assert_eq!(
+        response.host_config.restart_policy,
+        Some(RestartPolicy::on_failure(3))
+    );
+
+    containers.remove(&id).send().await?;
+
+    Ok(())
+}
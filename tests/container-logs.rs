@@ -0,0 +1,40 @@
+use futures_util::stream::TryStreamExt;
+use longshoreman::{containers::LogOutput, Docker, Result};
+
+#[tokio::test]
+async fn test() -> Result<()> {
+    let docker = Docker::new();
+    let images = docker.images();
+    let containers = docker.containers();
+
+    let image = "alpine";
+
+    // Pull image
+    images.pull(image).tag("latest").send().await?;
+
+    // Create a container which writes to stdout and stderr
+    let id = containers
+        .create(image)
+        .cmd(vec!["sh", "-c", "echo hello; echo world >&2"])
+        .send()
+        .await?
+        .id;
+
+    // Run it to completion
+    containers.start(&id).send().await?;
+    containers.wait(&id).send().await?;
+
+    // Read the logs
+    let logs: Vec<LogOutput> = containers
+        .logs(&id)
+        .tty(false)
+        .stream()
+        .try_collect()
+        .await?;
+    assert!(logs.contains(&LogOutput::StdOut("hello\n".into())));
+    assert!(logs.contains(&LogOutput::StdErr("world\n".into())));
+
+    containers.remove(&id).send().await?;
+
+    Ok(())
+}
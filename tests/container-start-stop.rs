@@ -0,0 +1,47 @@
+use longshoreman::{containers::StateChange, Docker, Result};
+use std::time::Duration;
+
+#[tokio::test]
+async fn test() -> Result<()> {
+    let docker = Docker::new();
+    let images = docker.images();
+    let containers = docker.containers();
+
+    let image = "alpine";
+
+    // Pull image
+    images.pull(image).tag("latest").send().await?;
+
+    // Create a long-running container
+    let id = containers
+        .create(image)
+        .cmd(vec!["sleep", "60"])
+        .send()
+        .await?
+        .id;
+
+    // Start it, twice
+    assert_eq!(containers.start(&id).send().await?, StateChange::Changed);
+    assert_eq!(containers.start(&id).send().await?, StateChange::Unchanged);
+
+    // Restart it
+    containers
+        .restart(&id)
+        .timeout(Duration::from_secs(1))
+        .send()
+        .await?;
+    assert!(containers.inspect(&id).send().await?.state.running);
+
+    // Stop it, twice
+    let stop = || containers.stop(&id).timeout(Duration::from_secs(1)).send();
+    assert_eq!(stop().await?, StateChange::Changed);
+    assert_eq!(stop().await?, StateChange::Unchanged);
+
+    // Start it again, and kill it
+    containers.start(&id).send().await?;
+    containers.kill(&id).signal("SIGKILL").send().await?;
+
+    containers.remove(&id).force(true).send().await?;
+
+    Ok(())
+}
@@ -0,0 +1,30 @@
+use longshoreman::{Docker, Result};
+
+#[tokio::test]
+async fn test() -> Result<()> {
+    let docker = Docker::new();
+    let images = docker.images();
+    let containers = docker.containers();
+
+    let image = "alpine";
+
+    // Pull image
+    images.pull(image).tag("latest").send().await?;
+
+    // Create a container which exits with a known status
+    let id = containers
+        .create(image)
+        .cmd(vec!["sh", "-c", "exit 3"])
+        .send()
+        .await?
+        .id;
+
+    // Start it, and wait for it to exit
+    containers.start(&id).send().await?;
+    let response = containers.wait(&id).send().await?;
+    assert_eq!(response.status_code, 3);
+
+    containers.remove(&id).send().await?;
+
+    Ok(())
+}
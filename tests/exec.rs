@@ -0,0 +1,36 @@
+use longshoreman::{Docker, Result};
+
+#[tokio::test]
+async fn test() -> Result<()> {
+    let docker = Docker::new();
+    let images = docker.images();
+    let containers = docker.containers();
+
+    let image = "alpine";
+
+    // Pull image
+    images.pull(image).tag("latest").send().await?;
+
+    // Create and start a long-running container
+    let id = containers
+        .create(image)
+        .cmd(vec!["sleep", "60"])
+        .send()
+        .await?
+        .id;
+    containers.start(&id).send().await?;
+
+    // Run a command inside it
+    let output = docker
+        .exec()
+        .create(&id, vec!["sh", "-c", "echo out; echo err >&2; exit 2"])
+        .output()
+        .await?;
+    assert_eq!(output.exit_code, 2);
+    assert_eq!(output.stdout, b"out\n");
+    assert_eq!(output.stderr, b"err\n");
+
+    containers.remove(&id).force(true).send().await?;
+
+    Ok(())
+}